@@ -0,0 +1,26 @@
+#ifndef POSEIDON_BN254_H
+#define POSEIDON_BN254_H
+
+/* Generated with `cbindgen --config cbindgen.toml --output include/poseidon_bn254.h`, do not edit. */
+
+#include <stdint.h>
+
+/**
+ * `hash_with_domain([a, b], domain)` with the signature of the zktrie
+ * `HashScheme` callback.
+ *
+ * Inputs and output are 32-byte little-endian field elements, inputs must
+ * be canonical. Returns NULL on success, otherwise a static NUL-terminated
+ * message and `out` is not written.
+ *
+ * # Safety
+ *
+ * `a`, `b` and `domain` must point to 32 readable bytes, `out` to 32
+ * writable bytes.
+ */
+const char *poseidon_bn254_hash_scheme(const uint8_t *a,
+                                       const uint8_t *b,
+                                       const uint8_t *domain,
+                                       uint8_t *out);
+
+#endif  /* POSEIDON_BN254_H */
@@ -0,0 +1,100 @@
+//! C ABI of `poseidon-bn254`, see `include/poseidon_bn254.h`.
+
+use poseidon_bn254::{hash_with_domain, Fr, PrimeField};
+use std::ffi::c_char;
+use std::ptr;
+
+/// `hash_with_domain([a, b], domain)` with the signature of the zktrie
+/// `HashScheme` callback.
+///
+/// Inputs and output are 32-byte little-endian field elements, inputs must
+/// be canonical. Returns NULL on success, otherwise a static NUL-terminated
+/// message and `out` is not written.
+///
+/// # Safety
+///
+/// `a`, `b` and `domain` must point to 32 readable bytes, `out` to 32
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_bn254_hash_scheme(
+    a: *const u8,
+    b: *const u8,
+    domain: *const u8,
+    out: *mut u8,
+) -> *const c_char {
+    if a.is_null() || b.is_null() || domain.is_null() || out.is_null() {
+        return c"null pointer".as_ptr();
+    }
+    // SAFETY: the pointers are valid for 32 bytes, see above
+    let Some(a) = (unsafe { read_fr(a) }) else {
+        return c"a is not a canonical field element".as_ptr();
+    };
+    let Some(b) = (unsafe { read_fr(b) }) else {
+        return c"b is not a canonical field element".as_ptr();
+    };
+    let Some(domain) = (unsafe { read_fr(domain) }) else {
+        return c"domain is not a canonical field element".as_ptr();
+    };
+
+    let hash = hash_with_domain(&[a, b], domain).to_repr();
+    unsafe { ptr::copy_nonoverlapping(hash.as_ptr(), out, hash.len()) };
+    ptr::null()
+}
+
+unsafe fn read_fr(src: *const u8) -> Option<Fr> {
+    let mut repr = [0u8; 32];
+    ptr::copy_nonoverlapping(src, repr.as_mut_ptr(), repr.len());
+    Fr::from_repr_vartime(repr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn call(a: &[u8; 32], b: &[u8; 32], domain: &[u8; 32]) -> Result<[u8; 32], String> {
+        let mut out = [0u8; 32];
+        let err = unsafe {
+            poseidon_bn254_hash_scheme(a.as_ptr(), b.as_ptr(), domain.as_ptr(), out.as_mut_ptr())
+        };
+        if err.is_null() {
+            Ok(out)
+        } else {
+            Err(unsafe { CStr::from_ptr(err) }.to_str().unwrap().to_string())
+        }
+    }
+
+    #[test]
+    fn test_hash_scheme() {
+        let (a, b, domain) = (Fr::from(1u64), Fr::from(2u64), Fr::from(256u64));
+        let expected = hash_with_domain(&[a, b], domain).to_repr();
+        assert_eq!(
+            call(&a.to_repr(), &b.to_repr(), &domain.to_repr()),
+            Ok(expected)
+        );
+        let zero = [0u8; 32];
+        assert_eq!(
+            call(&zero, &zero, &zero),
+            Ok(hash_with_domain(&[Fr::zero(), Fr::zero()], Fr::zero()).to_repr())
+        );
+
+        // the modulus itself is not canonical
+        let mut modulus = (-Fr::one()).to_repr();
+        modulus[0] += 1;
+        assert_eq!(
+            call(&zero, &modulus, &zero),
+            Err("b is not a canonical field element".to_string())
+        );
+        assert_eq!(
+            call(&zero, &zero, &[0xff; 32]),
+            Err("domain is not a canonical field element".to_string())
+        );
+
+        let mut out = [0u8; 32];
+        let err = unsafe {
+            poseidon_bn254_hash_scheme(ptr::null(), zero.as_ptr(), zero.as_ptr(), out.as_mut_ptr())
+        };
+        assert_eq!(unsafe { CStr::from_ptr(err) }, c"null pointer");
+        assert_eq!(out, zero);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Fr, Mds};
+use crate::{Fr, Mds, State};
 
 pub const T: usize = 3;
 pub const RATE: usize = 2;
@@ -1389,3 +1389,2715 @@ pub const MDS: Mds = [
         ]),
     ],
 ];
+
+/// Sparse partial rounds, see `imp::permute_sparse`. Derived from
+/// [`ROUND_CONSTANTS`] and [`MDS`] by `imp::tests::derive_sparse`.
+pub const SPARSE_PRE_MDS: Mds = [
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15476133327266186208,
+            10598338402256349781,
+            7701464610378726588,
+            1652049073940316358,
+        ]),
+        Fr::from_raw([
+            8726449643484246893,
+            17800103023430280315,
+            17732896993093103117,
+            3139215236411295113,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            6524160765819221277,
+            7062759061634624658,
+            4576744560523845667,
+            2193007419903062500,
+        ]),
+        Fr::from_raw([
+            10399508743233800090,
+            8161475092885183537,
+            7758841984966878555,
+            3265596275684752493,
+        ]),
+        Fr::from_raw([
+            6502727205386675616,
+            665651975050972568,
+            16732965410732695363,
+            1288357426300628616,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            4649090315204437095,
+            5210456304664984290,
+            14349208667469403796,
+            2006570410777139133,
+        ]),
+        Fr::from_raw([
+            13112555764313651145,
+            14845572983908402993,
+            16189051960808303235,
+            1947761574688030359,
+        ]),
+        Fr::from_raw([
+            9961667143156392893,
+            1546134903879383088,
+            11272296870048650616,
+            2457864846826447431,
+        ]),
+    ],
+];
+
+pub const SPARSE_PRE_CONSTANTS: State = [
+    Fr::from_raw([
+        1714630715101799769,
+        14370321670889575288,
+        16856964725142963021,
+        1176014593347144415,
+    ]),
+    Fr::from_raw([
+        17223286331472180518,
+        1343823262809070600,
+        12563239470639279337,
+        2301440021445592357,
+    ]),
+    Fr::from_raw([
+        7554754223319917700,
+        814681071396583973,
+        17270240958395463567,
+        236836177484620554,
+    ]),
+];
+
+pub const SPARSE_PARTIAL_CONSTANTS: [Fr; PARTIAL_ROUNDS] = [
+    Fr::from_raw([
+        14289692580852082315,
+        1460528266136100669,
+        5370564806373602510,
+        1845487610700299902,
+    ]),
+    Fr::from_raw([
+        8650866219163298038,
+        6084078866980823121,
+        11787456641210615072,
+        1994075656040316591,
+    ]),
+    Fr::from_raw([
+        2740849035905901922,
+        4493178439515051574,
+        5166931338119995235,
+        716875060191368657,
+    ]),
+    Fr::from_raw([
+        17464603002390522506,
+        5658074679193827237,
+        11323498463640773451,
+        2614295774769416120,
+    ]),
+    Fr::from_raw([
+        1155560843819266002,
+        14516906979602344652,
+        17011474362643431967,
+        878515849675878278,
+    ]),
+    Fr::from_raw([
+        10440342412677263173,
+        3481699546957524851,
+        12405713720709853507,
+        3158907253864379106,
+    ]),
+    Fr::from_raw([
+        16124397233231089542,
+        1329776877303428137,
+        1758794141885375534,
+        548067601218148322,
+    ]),
+    Fr::from_raw([
+        6941510486916583125,
+        17788595173371745829,
+        5031812187042201639,
+        256212732606402637,
+    ]),
+    Fr::from_raw([
+        6170067961171881554,
+        10626875458001697722,
+        13686896681391806702,
+        2160551727371015417,
+    ]),
+    Fr::from_raw([
+        8364208438132421521,
+        15708221604426087296,
+        17225074231939006186,
+        2247978209997964958,
+    ]),
+    Fr::from_raw([
+        9943138406032894248,
+        3076610027969599263,
+        17397376877287784053,
+        2664182294517370405,
+    ]),
+    Fr::from_raw([
+        6997636622553906784,
+        1700692271443724448,
+        15807713844484686993,
+        1992731415204971100,
+    ]),
+    Fr::from_raw([
+        188305831496603514,
+        16759644431021028104,
+        9473706096048890551,
+        3314874515727885467,
+    ]),
+    Fr::from_raw([
+        17962861397284752638,
+        13824207601749402731,
+        6214468479148349025,
+        2360154813863641096,
+    ]),
+    Fr::from_raw([
+        3495506164924257100,
+        7599497122272284781,
+        9178824625216852064,
+        85710383991653813,
+    ]),
+    Fr::from_raw([
+        12559081399894137654,
+        6464519588077411810,
+        13660486200809147433,
+        1452093531245628026,
+    ]),
+    Fr::from_raw([
+        1897017179402347515,
+        6129505370877284065,
+        2771806994431855759,
+        3109086719704888845,
+    ]),
+    Fr::from_raw([
+        17182412315510230653,
+        6640874269832097794,
+        5579734305693488360,
+        1948154009054030614,
+    ]),
+    Fr::from_raw([
+        406546361841021877,
+        5944712222062679321,
+        12874020233210972365,
+        2677043182283725558,
+    ]),
+    Fr::from_raw([
+        17598216475619662541,
+        3312326948664899740,
+        12727220985175943142,
+        2393366610649448175,
+    ]),
+    Fr::from_raw([
+        10363045421994320862,
+        7246815351854368416,
+        3589172027683693647,
+        100544006370413514,
+    ]),
+    Fr::from_raw([
+        6265196121918598357,
+        6969070686093841607,
+        2789377473048208096,
+        1417963121134459731,
+    ]),
+    Fr::from_raw([
+        6091184463071827826,
+        8863772959256066257,
+        4048662742783638926,
+        1659055448852400558,
+    ]),
+    Fr::from_raw([
+        7979737667598098578,
+        960315397551341189,
+        10153376460052652360,
+        90116134698298636,
+    ]),
+    Fr::from_raw([
+        5396630060837276647,
+        7424014243931643642,
+        16340175886873429023,
+        2193635679946613986,
+    ]),
+    Fr::from_raw([
+        13934611648183629324,
+        13094481140058463542,
+        805691820302813916,
+        3436901071277391447,
+    ]),
+    Fr::from_raw([
+        4162707605963242205,
+        11385036806429227493,
+        1676614084914175834,
+        1249201768373726696,
+    ]),
+    Fr::from_raw([
+        17884797868100044020,
+        4369545315341352106,
+        7496621653483393912,
+        1033645618418870703,
+    ]),
+    Fr::from_raw([
+        11489570401853121652,
+        12305685069938166770,
+        7225160248399415918,
+        786113432047412517,
+    ]),
+    Fr::from_raw([
+        9451536019622007731,
+        7658820780651846985,
+        13563749773516906288,
+        418570356670407666,
+    ]),
+    Fr::from_raw([
+        10445352922462032909,
+        4121283022412295312,
+        15572787041029645167,
+        985162325711835146,
+    ]),
+    Fr::from_raw([
+        4640936568031883158,
+        8105631703489053077,
+        11625162732483837553,
+        1852745562670127910,
+    ]),
+    Fr::from_raw([
+        5826449245345292423,
+        10378162345401809378,
+        6966383132293815010,
+        1910156884667288884,
+    ]),
+    Fr::from_raw([
+        12606122686833026378,
+        17555972859220886307,
+        2325155582070238053,
+        1581506069386120066,
+    ]),
+    Fr::from_raw([
+        6210756093857220837,
+        10451206438486409000,
+        2590304191309933632,
+        3429841227869468327,
+    ]),
+    Fr::from_raw([
+        973909276469452380,
+        10253644237143279745,
+        751951197817534101,
+        921803837738134710,
+    ]),
+    Fr::from_raw([
+        14969300459525099349,
+        5756182934083338640,
+        5235169977510498756,
+        1578527459262479443,
+    ]),
+    Fr::from_raw([
+        948722728035162589,
+        1658670370158716001,
+        16027651879997138983,
+        3443726230710035023,
+    ]),
+    Fr::from_raw([
+        9517428939060340260,
+        6448902366762092011,
+        6016213593507995561,
+        1990547110556536171,
+    ]),
+    Fr::from_raw([
+        14553141836761116974,
+        17640375803183557405,
+        13011865037956149359,
+        501837317329145813,
+    ]),
+    Fr::from_raw([
+        4293950828322985602,
+        1395074616204982575,
+        17474357756855294693,
+        831545251034150100,
+    ]),
+    Fr::from_raw([
+        2534486755040833530,
+        11974765562276562922,
+        1827490572056305917,
+        388997895392687279,
+    ]),
+    Fr::from_raw([
+        5216910535138379627,
+        7717571183741260178,
+        4131757938751567904,
+        1115499256940808185,
+    ]),
+    Fr::from_raw([
+        14062926664418260264,
+        9204146916635525963,
+        11971796903478636670,
+        2448887647196692244,
+    ]),
+    Fr::from_raw([
+        5622989458985199627,
+        8686986870221446049,
+        13204433311739620105,
+        1208538797082302239,
+    ]),
+    Fr::from_raw([
+        15211798182754884133,
+        13146904419954891357,
+        6473923775982244829,
+        2409553130953597252,
+    ]),
+    Fr::from_raw([
+        4361807750120479495,
+        12841317025655169690,
+        12472557129372806800,
+        2248416816055033209,
+    ]),
+    Fr::from_raw([
+        13944552384861171820,
+        7422170291980897571,
+        5250605941585543918,
+        1479544923982571462,
+    ]),
+    Fr::from_raw([
+        1122651622202000683,
+        10903617331829307933,
+        7848470885194917251,
+        101591943357216172,
+    ]),
+    Fr::from_raw([
+        10811664670639605978,
+        7919148534383788172,
+        1099098923707899392,
+        1564648401049597157,
+    ]),
+    Fr::from_raw([
+        6330148713315424045,
+        15849812811511556661,
+        6387624778316122120,
+        2847286930458313089,
+    ]),
+    Fr::from_raw([
+        11501566808615883483,
+        4645772001554378964,
+        7442173160986274369,
+        2400100245134675485,
+    ]),
+    Fr::from_raw([
+        7431082716333928448,
+        14836809555506545825,
+        4676432071926146717,
+        2088121940090353532,
+    ]),
+    Fr::from_raw([
+        1767661858238841582,
+        7648396891090685843,
+        16050543562807424912,
+        365301301965362436,
+    ]),
+    Fr::from_raw([
+        17629803170831078310,
+        7553064672714956552,
+        2004873818463204481,
+        1503267581116882624,
+    ]),
+    Fr::from_raw([
+        4642917309040038032,
+        16374207193628720299,
+        5888633512474424074,
+        1681798858067886226,
+    ]),
+    Fr::from_raw([0, 0, 0, 0]),
+];
+
+pub const SPARSE_FIRST_ROWS: [State; PARTIAL_ROUNDS] = [
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            4230877755477551921,
+            1912188362635038470,
+            7990048312946897699,
+            283869003097829815,
+        ]),
+        Fr::from_raw([
+            16143299782754939671,
+            6269338598749582536,
+            6109101215548924275,
+            1541510854424451113,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            12725326863204133607,
+            17652462132158520562,
+            15882411857251131526,
+            2422115230572163392,
+        ]),
+        Fr::from_raw([
+            12608560265598939861,
+            17388201569508210667,
+            5362207879628427769,
+            262066379189696621,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9047870877587166735,
+            7598676978957598030,
+            9926456014502394306,
+            2885052133982080238,
+        ]),
+        Fr::from_raw([
+            15279955520644303089,
+            3047924397834892469,
+            14561674186950675252,
+            1279941789503188882,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1425648748480253764,
+            12279719024429075390,
+            9088517990720768759,
+            3430981320801484343,
+        ]),
+        Fr::from_raw([
+            5547594710008906936,
+            15631470199228115669,
+            17374103224208318611,
+            2134316293819347281,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6608683353282969124,
+            16975293661644523932,
+            18235719976702277513,
+            3363104473702333151,
+        ]),
+        Fr::from_raw([
+            809639362751430950,
+            5659601775742604117,
+            1961180223491513488,
+            2224826694775071111,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            404922816345567236,
+            9354484602901343222,
+            6731614096876984209,
+            3266121068044379298,
+        ]),
+        Fr::from_raw([
+            1667696200712463896,
+            8580456609260680793,
+            16758221046700277799,
+            1567113533009251697,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7227398089802285312,
+            17827520554799924670,
+            16938925732206962037,
+            1010888792817287542,
+        ]),
+        Fr::from_raw([
+            5629348007779078732,
+            13064629990063865872,
+            17785076386149206967,
+            443035502770205825,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6922442446009502668,
+            10003092159026953329,
+            5865363934029955600,
+            2090803534731165581,
+        ]),
+        Fr::from_raw([
+            8967709660637768887,
+            3989914616078964462,
+            2885270939545896435,
+            865449305864024877,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6385956039587056439,
+            2861013701251914887,
+            6684780365084491815,
+            2512766838723355275,
+        ]),
+        Fr::from_raw([
+            4716826748121460778,
+            11516741637673238150,
+            8304649459054102528,
+            3392398199107570132,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            2946512376780566927,
+            8120678281971275669,
+            18353166128163010032,
+            222102448361303174,
+        ]),
+        Fr::from_raw([
+            1150071133031119601,
+            16320149409660548262,
+            12983779449837232320,
+            1793989776158472876,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17506856507627200849,
+            3536056593794131177,
+            9875102661289413111,
+            3257618636138097606,
+        ]),
+        Fr::from_raw([
+            1773723834234308616,
+            8733788055339986972,
+            3332113349829052359,
+            753490182224088708,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            5359734679848992784,
+            2554345071788582175,
+            4773560097717017913,
+            519235803870258068,
+        ]),
+        Fr::from_raw([
+            15000784957781037780,
+            16817546647288824519,
+            6544825122965301675,
+            1295679278411985037,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8960889661510373948,
+            8745397037065053754,
+            2624713106607124104,
+            1376347889273055760,
+        ]),
+        Fr::from_raw([
+            8168242101471593458,
+            2784578361910607118,
+            9195696794983238837,
+            3318486492059771,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8887499162391793183,
+            13593917119527522940,
+            15980901149337798388,
+            464417097405214332,
+        ]),
+        Fr::from_raw([
+            512570646941822542,
+            17288026133228503363,
+            8278756115493549407,
+            117023717526747570,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14387389689812630442,
+            17997946607969177570,
+            9170199836361301320,
+            269507059670091143,
+        ]),
+        Fr::from_raw([
+            18363990137338705591,
+            930855370662956173,
+            18379910120797369850,
+            2481127815740349370,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8451929141288979958,
+            812193926245120297,
+            3255504621750892638,
+            247934349170355220,
+        ]),
+        Fr::from_raw([
+            732729992577685081,
+            11779509780189888491,
+            10409554589187930602,
+            693958238037770483,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11893981741975751327,
+            17092846832325351251,
+            14108291481023652654,
+            3233915976846554992,
+        ]),
+        Fr::from_raw([
+            13405846311817385438,
+            597787820283229095,
+            715713563684634917,
+            276762981055208992,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6475734202719030609,
+            15051985543796979021,
+            1406745748167098890,
+            449149429314949125,
+        ]),
+        Fr::from_raw([
+            10524060841766459764,
+            226356507764459708,
+            17492830469330676596,
+            2267033432753987206,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17458920866628553219,
+            2293537392392535585,
+            18009706370427802250,
+            3031840954029313788,
+        ]),
+        Fr::from_raw([
+            11651715075663520635,
+            15417776454925712827,
+            5465118740144119694,
+            786810674309867084,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            18382030019436858846,
+            2509812600141889512,
+            15061398318979516701,
+            2186165861468085221,
+        ]),
+        Fr::from_raw([
+            10062402513649040201,
+            6776022429431771094,
+            17046277626155877629,
+            2111909002295439690,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15054395616064931776,
+            15855591219873547220,
+            7304837060526127130,
+            3373509263445861238,
+        ]),
+        Fr::from_raw([
+            15170553557012632980,
+            8245346669357151404,
+            6571329504715108084,
+            280100486605865572,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            4728558812939928565,
+            16560949102019494464,
+            14869279121395013676,
+            471599440883739978,
+        ]),
+        Fr::from_raw([
+            11001514484135261393,
+            2778664721413847510,
+            3099933580435624612,
+            1981293360071865302,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            18246414211453894977,
+            12197281195289207010,
+            1291144080270943096,
+            3348857239200086173,
+        ]),
+        Fr::from_raw([
+            16519590460486399432,
+            17253337382697247340,
+            5416499672243572717,
+            587390594511983530,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10183787136296436256,
+            18423595202974223465,
+            408860917514302117,
+            2092604937805072248,
+        ]),
+        Fr::from_raw([
+            11479077501162416596,
+            15064235216870894654,
+            15730848353943354300,
+            2452397714295154710,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            12650729470193181448,
+            14350713064529075997,
+            3563716890911205077,
+            632451293521564035,
+        ]),
+        Fr::from_raw([
+            14981946556620413049,
+            8480769143170689422,
+            15038517056977126111,
+            1394377379580059926,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17162119491848460212,
+            13605935410940702230,
+            1891605051691180775,
+            3284427897282200085,
+        ]),
+        Fr::from_raw([
+            989776919036806124,
+            4537231931844586200,
+            13373217634120741232,
+            1577174868840445711,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            5670616089421893926,
+            5947084383282072319,
+            880747568369865096,
+            3063902822609573407,
+        ]),
+        Fr::from_raw([
+            1610371257061445410,
+            11900603058080282395,
+            16600190855048860234,
+            2670826913332196614,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11203034296647894847,
+            16802356253362739038,
+            418979515685126352,
+            1533027564101941983,
+        ]),
+        Fr::from_raw([
+            12186277763758672607,
+            10177623023202144660,
+            5753207535949352014,
+            2193249021695358428,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9410457490204260840,
+            14856274399590979851,
+            17325676658441833951,
+            1335828825902250843,
+        ]),
+        Fr::from_raw([
+            6992156174325046062,
+            3966815215024179017,
+            6305576949754867144,
+            1100513932703735515,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3372991741843960439,
+            16186082104400337936,
+            14795838668167606917,
+            434972413813419171,
+        ]),
+        Fr::from_raw([
+            13873354775569907575,
+            7022763385761888573,
+            18272191024248337739,
+            904127028452236547,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6763070194141686032,
+            7091688491779628176,
+            7325601799159884156,
+            82338066746040207,
+        ]),
+        Fr::from_raw([
+            7812277195908656840,
+            1323817245878360104,
+            4521900449960647870,
+            87828957088008723,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15687778591426793663,
+            4604586929305491135,
+            4295676563632028507,
+            2297340546645060088,
+        ]),
+        Fr::from_raw([
+            6787900975101276659,
+            1843220457652504767,
+            3833452934132761214,
+            1078492612460284365,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            5238116212479327129,
+            5632535850334905810,
+            2756977358036168357,
+            547021367890565749,
+        ]),
+        Fr::from_raw([
+            7366893723893544101,
+            7488245056645326699,
+            2688287103399005181,
+            2129061510018328816,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17649957830777926882,
+            18433106606463653602,
+            14753587752008290736,
+            531998217376785816,
+        ]),
+        Fr::from_raw([
+            7920955116338554132,
+            2549848021627859057,
+            7858118069501148580,
+            1018743092962964104,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1758646060868223258,
+            14475791882524167412,
+            17823347072976350664,
+            1200370337191873182,
+        ]),
+        Fr::from_raw([
+            5611006676160121745,
+            6447099771075880049,
+            14493139620184096888,
+            819310183106380826,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            12318199181802200040,
+            17780895900654158396,
+            9426459073672040475,
+            1687393419939498257,
+        ]),
+        Fr::from_raw([
+            14880656019632088818,
+            16410749871016975315,
+            10607384623780118821,
+            3129778932040033776,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3285514305874164232,
+            17166324087446241593,
+            3027064790196640297,
+            2370127167553280109,
+        ]),
+        Fr::from_raw([
+            2935800700925973351,
+            5190229479738038542,
+            7299939691592645668,
+            3361699205238401551,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15744874786134315207,
+            2702810113032167329,
+            17053572563620548149,
+            757433330043998839,
+        ]),
+        Fr::from_raw([
+            8729562529870690261,
+            11472817187039664619,
+            16802912277559212074,
+            1067089539526800570,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10776237585676971982,
+            15363606475382707090,
+            2146265624418741196,
+            3394291524166257525,
+        ]),
+        Fr::from_raw([
+            1753336516384252195,
+            16580805094071217003,
+            6660098285893470906,
+            2300493349260705861,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3165245173549065892,
+            16816621900225385346,
+            10713271995259784489,
+            322456850458321515,
+        ]),
+        Fr::from_raw([
+            3504163800632228720,
+            10891330854849089249,
+            15970958629035285050,
+            1972318413474609425,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            16326210803401410497,
+            17729603351484395073,
+            16944549114494754071,
+            659764619396579055,
+        ]),
+        Fr::from_raw([
+            1029155802447446273,
+            18271018818549501854,
+            14084473196647246487,
+            2271655757943580123,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10988696190698766871,
+            2834855519880430838,
+            4554441011495855675,
+            2358349773855528369,
+        ]),
+        Fr::from_raw([
+            7397354037113983854,
+            7878964474253847323,
+            5010812730113096994,
+            1276400822736056778,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9767385683728257977,
+            11415177277256705207,
+            16288190597088200497,
+            3255769926075367145,
+        ]),
+        Fr::from_raw([
+            4364970596115413492,
+            10337420627857044695,
+            12209155746417247696,
+            1910071263112339162,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11337872508228097623,
+            973313494070604469,
+            4855578162269250597,
+            189648100745881544,
+        ]),
+        Fr::from_raw([
+            9632269525790709732,
+            12611050517074875105,
+            2040774512157084641,
+            3304436115221054680,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6010907698041460024,
+            2529796319070937730,
+            3050027474521957094,
+            440934025567507449,
+        ]),
+        Fr::from_raw([
+            14962542210731317124,
+            467789369260719185,
+            15639704408255736920,
+            1970485655657093342,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            16753098699031129762,
+            13819783909270875316,
+            14034152637789255442,
+            2328122183438807098,
+        ]),
+        Fr::from_raw([
+            5255438525484855836,
+            6289223497622558179,
+            4168137361614854686,
+            602074547832413088,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14272550852261083542,
+            18446413343156674693,
+            12246645008845904835,
+            2068219774424299180,
+        ]),
+        Fr::from_raw([
+            15913925051604325660,
+            15955866712253294729,
+            2927666037144594083,
+            812874948089366529,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15932209938844535893,
+            13288794002990455662,
+            8111088166206296333,
+            697601844828031507,
+        ]),
+        Fr::from_raw([
+            18311488811459530557,
+            12301393565481276275,
+            13929900297432436966,
+            2751169456419554431,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11111225287317928665,
+            15360267068213526925,
+            8915097554996919006,
+            2498595200306551627,
+        ]),
+        Fr::from_raw([
+            13790648909350630140,
+            3363020244570029372,
+            15445642672877321776,
+            584956705813076166,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            854052775291666672,
+            14033731128784969826,
+            15323517947316400572,
+            2405429247799886237,
+        ]),
+        Fr::from_raw([
+            13255498678098514826,
+            10543827661348256833,
+            12972153634324554882,
+            3236875019926122894,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1879758476999786867,
+            7096606718331686205,
+            10355118516991408641,
+            1270884440077270822,
+        ]),
+        Fr::from_raw([
+            5972709725560065604,
+            623159499100020881,
+            13223242005520210260,
+            1002031154277335638,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10875322647790816955,
+            5530299413266554762,
+            1182370489448493417,
+            2837933661121060977,
+        ]),
+        Fr::from_raw([
+            11077168825834436877,
+            16042747403481777867,
+            14826090045238759670,
+            2078467896876145367,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            16135155697973989906,
+            17011108829874507452,
+            2859860486777584922,
+            1785152844732533096,
+        ]),
+        Fr::from_raw([
+            394103590271806925,
+            4985008732247351713,
+            1914523114294532776,
+            520835448555645022,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            917775774643514072,
+            12178423665400233832,
+            13790652246990474655,
+            821623172650411242,
+        ]),
+        Fr::from_raw([
+            4732414876651791843,
+            11242675504237775846,
+            11196858563763627292,
+            3438114163408849496,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10997942240178506341,
+            13095520918701870423,
+            4571929601145925303,
+            1068927063389525862,
+        ]),
+        Fr::from_raw([
+            14470923247920398468,
+            2022547834758291135,
+            4409215455654739769,
+            567907344941528731,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7904398177600414916,
+            3342886092594012655,
+            9194302978356250075,
+            2381098219061512153,
+        ]),
+        Fr::from_raw([
+            2785745281918469113,
+            126743559153456531,
+            14392272119981169858,
+            1421773421021525455,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8949688881759454615,
+            15383689782083725104,
+            5122710289816328201,
+            1000362716978640213,
+        ]),
+        Fr::from_raw([
+            927335655117351293,
+            18241871390224886712,
+            13263346696463118012,
+            517679084847470667,
+        ]),
+    ],
+];
+
+pub const SPARSE_FIRST_COLUMNS: [State; PARTIAL_ROUNDS] = [
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11587743972008639109,
+            15014623272729965018,
+            1786380040701572452,
+            1332503373596713080,
+        ]),
+        Fr::from_raw([
+            17858039509472987069,
+            15262214297841547384,
+            10629335195211685044,
+            460323392731103727,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9588246118388685772,
+            15768694720931327539,
+            12693131357007247694,
+            2387395770562329141,
+        ]),
+        Fr::from_raw([
+            15011605364260005646,
+            14795873725319783305,
+            14968701558544759223,
+            1831650374934636434,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7898610225981397285,
+            17736348337287565496,
+            3926016334672546784,
+            2683759119601234866,
+        ]),
+        Fr::from_raw([
+            4089670849994931551,
+            5717910989439042308,
+            10432761435136010748,
+            3478606032539708158,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            6860176177590782017,
+            15114060251122990129,
+            17002815889621007847,
+            1318254497983994105,
+        ]),
+        Fr::from_raw([
+            9929407249487337906,
+            11959807652087597271,
+            4635839021424953669,
+            1841546721137411447,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11504870766918662613,
+            17797013900002871392,
+            12870910576009651327,
+            438811531398902374,
+        ]),
+        Fr::from_raw([
+            11833844101948847883,
+            9601201534895278211,
+            6393406801498052394,
+            1737660139068477513,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10710547812900177684,
+            14469054564231057673,
+            13218041483919706202,
+            3061529728592320378,
+        ]),
+        Fr::from_raw([
+            5486364187324699906,
+            7190918391933863265,
+            12346306426285968407,
+            1116002101632571184,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13137341174461417125,
+            12798343692780107029,
+            1652274968381805280,
+            34995747066430873,
+        ]),
+        Fr::from_raw([
+            5081616861723402187,
+            3590829550878933957,
+            10641011596961731954,
+            389340728600398262,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            12467435283101361359,
+            9382416038669431130,
+            16908674447601411684,
+            1711926510381278955,
+        ]),
+        Fr::from_raw([
+            9946309241092748077,
+            12296738982774435109,
+            12357520896947757089,
+            430168265724324637,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7013728899952783931,
+            13449988041338115530,
+            14317708153313665611,
+            2532102697895233893,
+        ]),
+        Fr::from_raw([
+            5636096605923103342,
+            17586410954593948921,
+            16734097733978371769,
+            174661518929683599,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15681256555236424031,
+            10967810365403886311,
+            506150794895899796,
+            2756965865003652411,
+        ]),
+        Fr::from_raw([
+            2447998519545872691,
+            3537747980057795650,
+            16739868878758650096,
+            2448886429558530280,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7476760096390557578,
+            4896595905930491209,
+            14094082577505925882,
+            1442669985234845994,
+        ]),
+        Fr::from_raw([
+            4241105716229417424,
+            6468355201328719513,
+            1780047856308169623,
+            1472851019385464013,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3811395717365601412,
+            17252929496401071080,
+            6733267071275569232,
+            2868857556193716494,
+        ]),
+        Fr::from_raw([
+            13518473588184275041,
+            13727392919621060264,
+            9201756999693162046,
+            1435978259442773490,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13842906631091607871,
+            1875357033738467235,
+            1734491169021377744,
+            2126682675583146340,
+        ]),
+        Fr::from_raw([
+            81224065677060089,
+            4704540492045639460,
+            4811014068936409975,
+            1964078094056901821,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1280440003182853395,
+            18032986062987843955,
+            18239433752287280240,
+            106941008413107351,
+        ]),
+        Fr::from_raw([
+            57567298457989603,
+            5510510875964422074,
+            9256644606524823434,
+            2338441300647428532,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14331462166158488090,
+            3793321105949557517,
+            248718799100100838,
+            952897297161256670,
+        ]),
+        Fr::from_raw([
+            4753405727987048442,
+            5946085472611471151,
+            3518608083647345082,
+            2892476690949493770,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10203353639903501719,
+            4323492641551883046,
+            5290256508527912942,
+            2461467202031392299,
+        ]),
+        Fr::from_raw([
+            13363998634117953860,
+            10678566113474950817,
+            11505562599182265632,
+            568628023234122436,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3280470037562812870,
+            11571712604401706611,
+            2022793518777676604,
+            1222916348095595910,
+        ]),
+        Fr::from_raw([
+            6671348702685762764,
+            5882165247560013450,
+            170429474903666354,
+            1714938450051278458,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14802170618659006706,
+            7258921372455556887,
+            11690234565747128933,
+            992463226158957017,
+        ]),
+        Fr::from_raw([
+            10450769377103187698,
+            12992031225625488323,
+            16071028558214054206,
+            494922160245209015,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3962131912750347217,
+            4642183587635271000,
+            2732759119522732437,
+            1448590111552203490,
+        ]),
+        Fr::from_raw([
+            13828430851788162487,
+            14702886337792164024,
+            13724560711408576038,
+            707885402661640923,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17158295904265015251,
+            10196890492411056048,
+            16185741437038705037,
+            532428722797716720,
+        ]),
+        Fr::from_raw([
+            7512961738379110726,
+            2289475278715780693,
+            13415878659888211007,
+            1334598714612770489,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7981163909751445859,
+            15719997056249038134,
+            6390195555558178136,
+            1290422992531869832,
+        ]),
+        Fr::from_raw([
+            10438259720767997874,
+            4945874205046297537,
+            505715716580813690,
+            1744496649992852176,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13997419689197301823,
+            1636619151659994876,
+            8025858735803053426,
+            2936567168645107808,
+        ]),
+        Fr::from_raw([
+            15485020582894276123,
+            6068329492448174319,
+            7523962445916402674,
+            1422474006821063761,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7520892304908053997,
+            13517073267820346201,
+            18029727828993154551,
+            12876305610238633,
+        ]),
+        Fr::from_raw([
+            10838558902720366279,
+            16643691196082138107,
+            10490036836949793553,
+            2485678758563786470,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1681848819241649388,
+            14548195150472470554,
+            16387047812233667093,
+            2928897770118655129,
+        ]),
+        Fr::from_raw([
+            2481264216829157944,
+            17516648089396183441,
+            1313370514993002349,
+            77395554751678989,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17096427906997894311,
+            12251949119318653093,
+            11846650427149441306,
+            1208377773475412406,
+        ]),
+        Fr::from_raw([
+            113577586282032245,
+            12671748971971911430,
+            15812287842026229702,
+            397377600824364860,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            3469592375657135075,
+            13042437644334203627,
+            17848361720389393009,
+            1755253248043876130,
+        ]),
+        Fr::from_raw([
+            6674700618938214326,
+            9992672838245700731,
+            7416734965311246506,
+            1791099089163763766,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8635168934489404914,
+            5167875439329090937,
+            13095687215324046459,
+            1089999354035971599,
+        ]),
+        Fr::from_raw([
+            5292081732198350992,
+            14074624725708227272,
+            12116668825485256398,
+            3408214644847197509,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            18084829503348641990,
+            11009245913386550641,
+            11346164762159729539,
+            3238547555138865424,
+        ]),
+        Fr::from_raw([
+            7984711506606200960,
+            12276119901519007489,
+            9786171139698514503,
+            1445571481729920877,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9693894611573621972,
+            6366543760896251531,
+            7744482705742867404,
+            1265683575863580676,
+        ]),
+        Fr::from_raw([
+            18265400966263278870,
+            7572634750768779329,
+            717119701291096653,
+            2995089725142347639,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9978382278636473104,
+            987412923786463735,
+            13619715677377817715,
+            1408448663034936223,
+        ]),
+        Fr::from_raw([
+            17445737293654463680,
+            15911415794496281315,
+            5076666150526496341,
+            3342203894625611793,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            16741419868623744997,
+            6461051658440340676,
+            1381103078341643567,
+            3353160435947734920,
+        ]),
+        Fr::from_raw([
+            16788506757847527684,
+            2474655686171428802,
+            724164080108140670,
+            140687250522359301,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9874289552854347177,
+            5797629740691457550,
+            2038041488099234065,
+            1800083358476898558,
+        ]),
+        Fr::from_raw([
+            1807840482098485352,
+            13883861406388608182,
+            5490713710704081468,
+            539022542337486529,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            4796098268672810413,
+            8531208860838490710,
+            17957354629117982579,
+            3040976313187302997,
+        ]),
+        Fr::from_raw([
+            3750868574609909938,
+            12827765181738808630,
+            5674924518939081322,
+            2715726777068230063,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1395131895979330400,
+            16893591742660527629,
+            7941890028344966850,
+            2126471829616576672,
+        ]),
+        Fr::from_raw([
+            1494381888823347577,
+            14067415471277033491,
+            11955266901859971255,
+            324266102215323821,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14860864788062477638,
+            15528430128759628201,
+            5293449022573247211,
+            1176929110383169113,
+        ]),
+        Fr::from_raw([
+            12422211637831847926,
+            13384308740990259412,
+            10057493805955937397,
+            1566802916609011865,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13177145445233161297,
+            12124970376988959382,
+            9356167335556887249,
+            128453773018649613,
+        ]),
+        Fr::from_raw([
+            5883683166702931321,
+            2549533861075713311,
+            13662322405239825912,
+            2369430475773340172,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7213615760411201396,
+            678315143608758705,
+            3900283116505036234,
+            1399461320168383689,
+        ]),
+        Fr::from_raw([
+            14493915044487237278,
+            1868245314092724726,
+            3487972147122468113,
+            2063215105486267383,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7704457965987926891,
+            7521394576719952625,
+            15072037425841187181,
+            555704255780842093,
+        ]),
+        Fr::from_raw([
+            14682780270715347280,
+            15496453160254136809,
+            15142629394660440174,
+            2134101782464664575,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13678466884400462984,
+            6221722380413953591,
+            4255186429610698583,
+            1800470496365730603,
+        ]),
+        Fr::from_raw([
+            9671632436101556245,
+            11834580993522022997,
+            7980101236199500712,
+            2951899257256470896,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10691446004572020334,
+            17829942909023531439,
+            6874157029088210075,
+            2835724026710176851,
+        ]),
+        Fr::from_raw([
+            14861386906215807656,
+            3050682257091893399,
+            14646556361209137628,
+            3352799241735162828,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11305958576998445044,
+            7338980252958363569,
+            700098036057894893,
+            709212617223370033,
+        ]),
+        Fr::from_raw([
+            12737113962450492927,
+            11131532214311475605,
+            1174907511518231643,
+            2895116907362231221,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            10412675777461732286,
+            5154632293858271039,
+            3561840827633490740,
+            3343469736017839496,
+        ]),
+        Fr::from_raw([
+            8399792917676393877,
+            8095131019741338138,
+            11674070277989595821,
+            1680456251284453269,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1558645365764514359,
+            17481221081750020309,
+            2380850888851740208,
+            2882118776378392151,
+        ]),
+        Fr::from_raw([
+            1278501847048067732,
+            9515280808695978506,
+            4349326464517120359,
+            2025087259724397777,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            9981941424801381216,
+            10295100940123560628,
+            5624274306080696349,
+            1008552894452488821,
+        ]),
+        Fr::from_raw([
+            13174705685643281866,
+            17045476458236518184,
+            8513878951831864205,
+            1212889025335290812,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            16642623897052369399,
+            1805675018589936988,
+            10980814190164074631,
+            337783224744301029,
+        ]),
+        Fr::from_raw([
+            4317981022313381223,
+            2880237590965191051,
+            1326996280811562924,
+            2565153637209445337,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            17281285795804225640,
+            17369917365257056600,
+            14172438457774813363,
+            2634679075025788711,
+        ]),
+        Fr::from_raw([
+            4469863962582968683,
+            17889793495191990907,
+            6064190300951675874,
+            497535320324786804,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            4542926069585963599,
+            5792354506453799370,
+            10523237359879938214,
+            900241544338743393,
+        ]),
+        Fr::from_raw([
+            10217959074288506335,
+            4449023309796251788,
+            13522674541391181558,
+            2439296714535630780,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            12276037872122672317,
+            3061315658361687518,
+            3208747199961463966,
+            2543395416383641724,
+        ]),
+        Fr::from_raw([
+            17214586730567397570,
+            6400293529855030322,
+            3245497176896589750,
+            3417311579329824432,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            1635677936809261365,
+            6002056029376085952,
+            14889935727355141004,
+            1351258414714455788,
+        ]),
+        Fr::from_raw([
+            11496932437668082265,
+            13073665170748561140,
+            3447220219904413050,
+            1220365700401539276,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            13211837852454790703,
+            17475411877907826703,
+            18325001897910869819,
+            3107645338536212903,
+        ]),
+        Fr::from_raw([
+            9417827610941349869,
+            5823093848529976947,
+            582197489694295076,
+            1542117010643045260,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            7427152157254267311,
+            6360704912640624430,
+            14737149082886769758,
+            203628729520641796,
+        ]),
+        Fr::from_raw([
+            2904110727101347928,
+            11078261814535760860,
+            17668070772544582888,
+            124073506674499936,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8795715067006163542,
+            8694391326930009735,
+            12061630928426255244,
+            426156234415054701,
+        ]),
+        Fr::from_raw([
+            9328999705217272405,
+            10043678968276051984,
+            6009719241413658973,
+            718266316697602296,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            11810536276627013247,
+            18302979877632347367,
+            2505868346520022517,
+            3348554292585998034,
+        ]),
+        Fr::from_raw([
+            10261260821747583057,
+            5311972139863367410,
+            445921066353999682,
+            552297777750322653,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            8477636833200754218,
+            9613491012660141014,
+            18195772419064254760,
+            2955026312857665988,
+        ]),
+        Fr::from_raw([
+            13284866057798827848,
+            17865061865146110312,
+            4694476266408388518,
+            1515852634926988478,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            14809536499052880498,
+            3129667374208434258,
+            16569287197079812332,
+            432565272047805253,
+        ]),
+        Fr::from_raw([
+            11534370767495517957,
+            8592635888670925214,
+            3468512729107866548,
+            402518268014177217,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            2422456617449704752,
+            2916206865436678551,
+            9289527625928632151,
+            2318497665792721504,
+        ]),
+        Fr::from_raw([
+            2948203635131557042,
+            142925105210612295,
+            8562643163253907974,
+            1033044223782614894,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18364386637727142283,
+            10718140578549479307,
+            12877775606795118513,
+            1196690043610129609,
+        ]),
+        Fr::from_raw([
+            15431936778085062513,
+            3227108208197516766,
+            13385661772669246410,
+            2984182855068918912,
+        ]),
+        Fr::from_raw([
+            3630540040312041895,
+            16631083012873467731,
+            963663003087537756,
+            1454699978888462131,
+        ]),
+    ],
+];
@@ -0,0 +1,251 @@
+//! Round constant and MDS generation from the Poseidon paper.
+//!
+//! This is a port of the reference `generate_parameters_grain.sage`: an
+//! 80-bit Grain LFSR seeded with the instance description produces the
+//! round constants (by rejection sampling) and then the points of a Cauchy
+//! MDS matrix. The first valid matrix is used, which is what the parameter
+//! sets shipped by this crate and by circomlib were generated with. Only
+//! prime fields are supported.
+
+use crate::PrimeField;
+
+/// S-box of the instance, it is part of the LFSR seed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sbox {
+    /// `x^alpha`
+    Pow(u64),
+    /// `x^-1`
+    Inverse,
+}
+
+/// Round constants and MDS matrix of a generated instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters<F, const T: usize> {
+    pub round_constants: Vec<[F; T]>,
+    pub mds: [[F; T]; T],
+}
+
+/// Generates the parameters of a width `T` instance over `F`.
+pub fn generate<F: PrimeField, const T: usize>(
+    sbox: Sbox,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> Parameters<F, T> {
+    let n = F::NUM_BITS as usize;
+    let modulus = modulus_bits::<F>();
+    let mut grain = Grain::new(sbox, n, T, full_rounds, partial_rounds);
+
+    let round_constants = (0..full_rounds + partial_rounds)
+        .map(|_| std::array::from_fn(|_| grain.next_field_element(&modulus)))
+        .collect();
+    let mds = grain.next_cauchy_matrix();
+
+    Parameters {
+        round_constants,
+        mds,
+    }
+}
+
+/// Round constants of a Poseidon2 instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poseidon2Constants<F, const T: usize> {
+    /// One row per full round, the first half of the full rounds first.
+    pub external: Vec<[F; T]>,
+    /// One constant per partial round, added to `state[0]`.
+    pub internal: Vec<F>,
+}
+
+/// Generates the round constants of a width `T` Poseidon2 instance over `F`.
+///
+/// Same LFSR as [`generate`], but the reference `poseidon2_rust_params.sage`
+/// only draws a single constant for each partial round. The linear layers
+/// are not generated.
+pub fn generate_poseidon2<F: PrimeField, const T: usize>(
+    sbox: Sbox,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> Poseidon2Constants<F, T> {
+    let n = F::NUM_BITS as usize;
+    let modulus = modulus_bits::<F>();
+    let mut grain = Grain::new(sbox, n, T, full_rounds, partial_rounds);
+
+    let full_row = |grain: &mut Grain| std::array::from_fn(|_| grain.next_field_element(&modulus));
+    let mut external: Vec<[F; T]> = (0..full_rounds / 2).map(|_| full_row(&mut grain)).collect();
+    let internal = (0..partial_rounds)
+        .map(|_| grain.next_field_element(&modulus))
+        .collect();
+    external.extend((0..full_rounds / 2).map(|_| full_row(&mut grain)));
+
+    Poseidon2Constants { external, internal }
+}
+
+/// The self-shrinking Grain LFSR of the reference implementation.
+#[derive(Clone, Debug)]
+pub struct Grain {
+    // bit i is the i-th oldest bit of the 80-bit register
+    state: u128,
+    n: usize,
+}
+
+impl Grain {
+    pub fn new(
+        sbox: Sbox,
+        field_bits: usize,
+        width: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Self {
+        let mut seed = Vec::with_capacity(80);
+        let mut push = |value: u64, bits: usize| {
+            seed.extend((0..bits).rev().map(|i| (value >> i) & 1 == 1));
+        };
+        // field type: 1 for prime fields
+        push(1, 2);
+        push(
+            match sbox {
+                Sbox::Pow(_) => 0,
+                Sbox::Inverse => 1,
+            },
+            4,
+        );
+        push(field_bits as u64, 12);
+        push(width as u64, 12);
+        push(full_rounds as u64, 10);
+        push(partial_rounds as u64, 10);
+        push((1 << 30) - 1, 30);
+
+        let state = seed
+            .iter()
+            .enumerate()
+            .fold(0u128, |acc, (i, bit)| acc | ((*bit as u128) << i));
+
+        let mut grain = Self {
+            state,
+            n: field_bits,
+        };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let bit = |i: usize| (self.state >> i) & 1;
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state = (self.state >> 1) | (new_bit << 79);
+        new_bit == 1
+    }
+
+    /// Output bits are taken in pairs: the second bit is kept if the first is set.
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.step();
+            let bit = self.step();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Next `n` bits, most significant first.
+    pub fn next_bits(&mut self) -> Vec<bool> {
+        (0..self.n).map(|_| self.next_bit()).collect()
+    }
+
+    /// Next field element, sampled by rejecting values not below the modulus.
+    fn next_field_element<F: PrimeField>(&mut self, modulus: &[bool]) -> F {
+        loop {
+            let bits = self.next_bits();
+            if bits.as_slice() < modulus {
+                return bits_to_field(&bits);
+            }
+        }
+    }
+
+    /// Cauchy matrix `1 / (x_i + y_j)` over distinct points, reduced modulo the field.
+    fn next_cauchy_matrix<F: PrimeField, const T: usize>(&mut self) -> [[F; T]; T] {
+        loop {
+            let points: Vec<F> = (0..2 * T)
+                .map(|_| bits_to_field(&self.next_bits()))
+                .collect();
+            let distinct = points
+                .iter()
+                .enumerate()
+                .all(|(i, p)| !points[..i].contains(p));
+            if !distinct {
+                continue;
+            }
+
+            let (xs, ys) = points.split_at(T);
+            let mut mds = [[F::ZERO; T]; T];
+            let mut valid = true;
+            for (row, x) in mds.iter_mut().zip(xs) {
+                for (entry, y) in row.iter_mut().zip(ys) {
+                    match Option::<F>::from((*x + y).invert()) {
+                        Some(inv) => *entry = inv,
+                        None => valid = false,
+                    }
+                }
+            }
+            if valid {
+                return mds;
+            }
+        }
+    }
+}
+
+/// Big-endian bits of the modulus, `F::NUM_BITS` long.
+fn modulus_bits<F: PrimeField>() -> Vec<bool> {
+    let hex = F::MODULUS.trim_start_matches("0x");
+    let bits: Vec<bool> = hex
+        .chars()
+        .flat_map(|c| {
+            let nibble = c.to_digit(16).expect("modulus is hex");
+            (0..4).rev().map(move |i| (nibble >> i) & 1 == 1)
+        })
+        .collect();
+    let skip = bits.len() - F::NUM_BITS as usize;
+    assert!(bits[..skip].iter().all(|bit| !bit));
+    bits[skip..].to_vec()
+}
+
+fn bits_to_field<F: PrimeField>(bits: &[bool]) -> F {
+    bits.iter().fold(F::ZERO, |acc, bit| {
+        let acc = acc.double();
+        if *bit {
+            acc + F::ONE
+        } else {
+            acc
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{widths, Fr, MDS, ROUND_CONSTANTS};
+
+    #[test]
+    fn test_reproduces_constants() {
+        let params = generate::<Fr, 3>(Sbox::Pow(5), 8, 57);
+        assert_eq!(params.round_constants, ROUND_CONSTANTS);
+        assert_eq!(params.mds, MDS);
+    }
+
+    #[test]
+    fn test_reproduces_widths() {
+        macro_rules! check {
+            ($($t:ident),*) => {$(
+                let poseidon = &widths::$t::POSEIDON;
+                let params = generate::<Fr, { widths::$t::T }>(
+                    Sbox::Pow(5),
+                    widths::$t::FULL_ROUNDS,
+                    widths::$t::PARTIAL_ROUNDS,
+                );
+                assert_eq!(params.round_constants, poseidon.round_constants());
+                assert_eq!(&params.mds, poseidon.mds());
+            )*};
+        }
+        check!(t2, t4, t5, t6, t7, t8, t9, t10, t11, t12, t13);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    code_chunk_to_fr, code_hash_to_bytes, imp, Field, Fr, PrimeField, State, Xof, EMPTY_HASH,
+    EMPTY_HASH_BYTES, POSEIDON_HASH_BYTES_IN_FIELD, RATE,
+};
+use std::io::{self, Read};
+use std::ops::AddAssign;
+
+/// Incremental version of [`hash_msg`](crate::hash_msg).
+///
+/// Elements can be fed in any number of [`update`](Self::update) calls, the
+/// digest is identical to hashing the concatenation with `hash_msg`.
+/// Unlike `hash_msg`, it never takes part in the zkvm hint protocol.
+#[derive(Clone, Debug)]
+pub struct MsgHasher {
+    state: State,
+    // next rate position to absorb into
+    pos: usize,
+    absorbed: usize,
+    expected_len: Option<usize>,
+}
+
+impl MsgHasher {
+    /// Same as `hash_msg(msg, Some(cap))`.
+    pub fn new(cap: u128) -> Self {
+        Self::with_cap(Fr::from_u128(cap), None)
+    }
+
+    /// Same as `hash_msg(msg, None)`, where `len` is `msg.len()`.
+    pub fn with_msg_len(len: usize) -> Self {
+        // same trick as hash_msg: len * (1 << 64)
+        Self::with_cap(Fr::from_raw([0, len as u64, 0, 0]), Some(len))
+    }
+
+    fn with_cap(cap: Fr, expected_len: Option<usize>) -> Self {
+        debug_assert_eq!(RATE, 2);
+        Self {
+            state: [cap, Fr::zero(), Fr::zero()],
+            pos: 0,
+            absorbed: 0,
+            expected_len,
+        }
+    }
+
+    pub fn update(&mut self, msg: &[Fr]) {
+        for elem in msg {
+            self.update_one(*elem);
+        }
+    }
+
+    pub fn update_one(&mut self, elem: Fr) {
+        self.state[1 + self.pos].add_assign(&elem);
+        self.pos += 1;
+        self.absorbed += 1;
+        if self.pos == RATE {
+            imp::permute(&mut self.state);
+            self.pos = 0;
+        }
+    }
+
+    pub fn finalize(self) -> Fr {
+        if self.absorbed == 0 && self.state[0].is_zero_vartime() {
+            return EMPTY_HASH;
+        }
+        self.finalize_state()[0]
+    }
+
+    /// Finishes absorption and returns the extendable output, see [`Xof`].
+    pub fn finalize_xof(self) -> Xof {
+        Xof::new(self.finalize_state())
+    }
+
+    pub(crate) fn finalize_state(mut self) -> State {
+        if let Some(len) = self.expected_len {
+            debug_assert_eq!(len, self.absorbed, "message length mismatch");
+        }
+        // a partial block, or a capacity-only message, still needs one permutation
+        if self.pos != 0 || self.absorbed == 0 {
+            imp::permute(&mut self.state);
+        }
+        self.state
+    }
+}
+
+/// Incremental version of [`hash_code`](crate::hash_code).
+///
+/// The total code length is part of the capacity, so it must be known up
+/// front. Bytes may be fed in slices of any size, partial
+/// [`POSEIDON_HASH_BYTES_IN_FIELD`] chunks are buffered until completed.
+#[derive(Clone, Debug)]
+pub struct CodeHasher {
+    inner: MsgHasher,
+    chunk: [u8; POSEIDON_HASH_BYTES_IN_FIELD],
+    chunk_len: usize,
+    len: usize,
+    written: usize,
+}
+
+impl CodeHasher {
+    pub fn new(len: usize) -> Self {
+        Self {
+            inner: MsgHasher::with_cap(Fr::from_raw([0, len as u64, 0, 0]), None),
+            chunk: [0u8; POSEIDON_HASH_BYTES_IN_FIELD],
+            chunk_len: 0,
+            len,
+            written: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut code: &[u8]) {
+        assert!(
+            self.written + code.len() <= self.len,
+            "code exceeds the declared length {}",
+            self.len
+        );
+        self.written += code.len();
+
+        if self.chunk_len != 0 {
+            let n = code
+                .len()
+                .min(POSEIDON_HASH_BYTES_IN_FIELD - self.chunk_len);
+            self.chunk[self.chunk_len..self.chunk_len + n].copy_from_slice(&code[..n]);
+            self.chunk_len += n;
+            code = &code[n..];
+            if self.chunk_len < POSEIDON_HASH_BYTES_IN_FIELD {
+                return;
+            }
+            self.inner.update_one(code_chunk_to_fr(&self.chunk));
+            self.chunk_len = 0;
+        }
+
+        let mut chunks = code.chunks_exact(POSEIDON_HASH_BYTES_IN_FIELD);
+        for chunk in chunks.by_ref() {
+            self.inner.update_one(code_chunk_to_fr(chunk));
+        }
+        let rem = chunks.remainder();
+        self.chunk[..rem.len()].copy_from_slice(rem);
+        self.chunk_len = rem.len();
+    }
+
+    pub fn finalize(mut self) -> [u8; 32] {
+        assert_eq!(
+            self.written, self.len,
+            "code is shorter than the declared length"
+        );
+        if self.len == 0 {
+            return EMPTY_HASH_BYTES;
+        }
+        if self.chunk_len != 0 {
+            self.inner
+                .update_one(code_chunk_to_fr(&self.chunk[..self.chunk_len]));
+        }
+        code_hash_to_bytes(&mut self.inner.finalize().to_repr())
+    }
+}
+
+/// Hashes exactly `len` bytes of code read from `reader`, same as [`hash_code`](crate::hash_code).
+pub fn hash_code_reader<R: Read>(mut reader: R, len: usize) -> io::Result<[u8; 32]> {
+    let mut hasher = CodeHasher::new(len);
+    let mut buf = [0u8; POSEIDON_HASH_BYTES_IN_FIELD * 128];
+    let mut remaining = len;
+    while remaining != 0 {
+        let n = remaining.min(buf.len());
+        reader.read_exact(&mut buf[..n])?;
+        hasher.update(&buf[..n]);
+        remaining -= n;
+    }
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_code, hash_msg};
+
+    #[test]
+    fn test_msg_hasher() {
+        let msg: Vec<_> = (0..13u64).map(Fr::from).collect();
+
+        for len in 0..msg.len() {
+            let msg = &msg[..len];
+            for cap in [None, Some(0u128), Some(1), Some(100)] {
+                let expected = hash_msg(msg, cap);
+
+                // split at every position to exercise leftovers across updates
+                for split in 0..=len {
+                    let mut hasher = match cap {
+                        Some(cap) => MsgHasher::new(cap),
+                        None => MsgHasher::with_msg_len(len),
+                    };
+                    hasher.update(&msg[..split]);
+                    hasher.update(&msg[split..]);
+                    assert_eq!(hasher.finalize(), expected);
+                }
+
+                let mut hasher = match cap {
+                    Some(cap) => MsgHasher::new(cap),
+                    None => MsgHasher::with_msg_len(len),
+                };
+                msg.iter().for_each(|elem| hasher.update_one(*elem));
+                assert_eq!(hasher.finalize(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_code_hasher() {
+        let code: Vec<u8> = (0..200u32).map(|i| (i * 7 + 3) as u8).collect();
+
+        for len in [0, 1, 30, 31, 32, 61, 62, 63, 93, 100, 200] {
+            let code = &code[..len];
+            let expected = hash_code(code);
+
+            for step in [1, 5, 30, 31, 32, 64, 200] {
+                let mut hasher = CodeHasher::new(len);
+                code.chunks(step).for_each(|chunk| hasher.update(chunk));
+                assert_eq!(hasher.finalize(), expected);
+            }
+
+            assert_eq!(hash_code_reader(code, len).unwrap(), expected);
+        }
+
+        assert!(hash_code_reader(&code[..10], 11).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// Function that produced a hint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum HintKind {
+    HashWithDomain = 1,
+    HashMsg = 2,
+    HashCode = 3,
+}
+
+impl HintKind {
+    pub(crate) fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            1 => Some(HintKind::HashWithDomain),
+            2 => Some(HintKind::HashMsg),
+            3 => Some(HintKind::HashCode),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for HintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HintKind::HashWithDomain => "hash_with_domain",
+            HintKind::HashMsg => "hash_msg",
+            HintKind::HashCode => "hash_code",
+        })
+    }
+}
+
+/// A zkvm hint that cannot be used by the call reading it.
+///
+/// `index` counts the hints read so far, `kind` and `len` describe the call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HintError {
+    /// The hint is not `HintRecord::SIZE` bytes long.
+    WrongLength {
+        index: usize,
+        kind: HintKind,
+        len: usize,
+        bytes: usize,
+    },
+    /// The hint was produced by another call, or over another input length.
+    TagMismatch {
+        index: usize,
+        kind: HintKind,
+        len: usize,
+        found_kind: u8,
+        found_len: u64,
+    },
+    /// The hinted output is not a canonical field element.
+    NonCanonical {
+        index: usize,
+        kind: HintKind,
+        len: usize,
+    },
+    /// The hint source, or the tape of a host replay, has no record left.
+    Exhausted {
+        index: usize,
+        kind: HintKind,
+        len: usize,
+    },
+}
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (HintError::WrongLength {
+            index, kind, len, ..
+        }
+        | HintError::TagMismatch {
+            index, kind, len, ..
+        }
+        | HintError::NonCanonical { index, kind, len }
+        | HintError::Exhausted { index, kind, len }) = self;
+        write!(f, "zkvm hint {index} for {kind}(len {len}): ")?;
+        match self {
+            HintError::WrongLength { bytes, .. } => write!(f, "malformed record of {bytes} bytes"),
+            HintError::TagMismatch {
+                found_kind,
+                found_len,
+                ..
+            } => match HintKind::from_u8(*found_kind) {
+                Some(found) => write!(f, "tag mismatch, got {found}(len {found_len})"),
+                None => write!(f, "tag mismatch, got unknown kind {found_kind}"),
+            },
+            HintError::NonCanonical { .. } => write!(f, "non-canonical field element"),
+            HintError::Exhausted { .. } => write!(f, "no hint left"),
+        }
+    }
+}
+
+impl std::error::Error for HintError {}
@@ -1,4 +1,4 @@
-use crate::{Fr, State, T};
+use crate::Fr;
 use std::mem::MaybeUninit;
 
 #[inline(always)]
@@ -14,35 +14,30 @@ pub(crate) fn sbox_inplace(val: &mut Fr) {
 }
 
 #[inline(always)]
-pub(crate) fn fill_state(state: &mut MaybeUninit<State>, val: &Fr) {
+pub(crate) fn fill_state<const W: usize>(state: &mut MaybeUninit<[Fr; W]>, val: &Fr) {
     unsafe {
-        for i in 0..T {
+        for i in 0..W {
             (state.as_mut_ptr() as *mut Fr).add(i).write(*val);
         }
     }
 }
 
 #[inline(always)]
-pub(crate) fn set_state(state: &mut State, new_state: &State) {
+pub(crate) fn set_state<const W: usize>(state: &mut [Fr; W], new_state: &[Fr; W]) {
     state.copy_from_slice(new_state);
 }
 
 #[inline(always)]
-pub(crate) fn init_state_with_cap_and_msg<'a>(
-    state: &'a mut MaybeUninit<State>,
+pub(crate) fn init_state_with_cap_and_msg<'a, const W: usize>(
+    state: &'a mut MaybeUninit<[Fr; W]>,
     cap: &Fr,
     msg: &[Fr],
-) -> &'a mut State {
-    match msg.len() {
-        0 => state.write([*cap, Fr::zero(), Fr::zero()]),
-        1 => state.write([*cap, msg[0], Fr::zero()]),
-        _ => state.write([*cap, msg[0], msg[1]]),
-    }
-}
-
-#[inline(always)]
-pub(crate) unsafe fn set_fr(dst: *mut Fr, val: &Fr) {
-    dst.write(*val);
+) -> &'a mut [Fr; W] {
+    let n = msg.len().min(W - 1);
+    let state = state.write([Fr::zero(); W]);
+    state[0] = *cap;
+    state[1..1 + n].copy_from_slice(&msg[..n]);
+    state
 }
 
 #[inline(always)]
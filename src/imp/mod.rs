@@ -1,4 +1,4 @@
-use crate::{Fr, State, FULL_ROUNDS, MDS, PARTIAL_ROUNDS, ROUND_CONSTANTS, T};
+use crate::{Fr, State, POSEIDON};
 use std::mem::MaybeUninit;
 use std::ops::{AddAssign, MulAssign};
 
@@ -6,52 +6,266 @@ use std::ops::{AddAssign, MulAssign};
 mod host;
 #[cfg(not(all(target_os = "zkvm", target_vendor = "succinct")))]
 pub(crate) use host::{
-    fill_state, init_state_with_cap_and_msg, mul_add_assign, sbox_inplace, set_fr, set_state,
+    fill_state, init_state_with_cap_and_msg, mul_add_assign, sbox_inplace, set_state,
 };
 
 #[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
 mod sp1;
 #[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
 pub(crate) use sp1::{
-    fill_state, init_state_with_cap_and_msg, mul_add_assign, sbox_inplace, set_fr, set_state,
+    fill_state, init_state_with_cap_and_msg, mul_add_assign, sbox_inplace, set_state,
 };
 
 #[inline(always)]
 pub fn permute(state: &mut State) {
-    const R_F: usize = FULL_ROUNDS / 2;
-    const R_P: usize = PARTIAL_ROUNDS;
+    POSEIDON.permute(state);
+}
+
+/// Permutation of any width `W`, the partial round count is implied by
+/// the number of round constants.
+#[inline(always)]
+pub(crate) fn permute_with<const W: usize>(
+    state: &mut [Fr; W],
+    round_constants: &[[Fr; W]],
+    mds: &[[Fr; W]; W],
+    full_rounds: usize,
+) {
+    let r_f = full_rounds / 2;
+    let r_p = round_constants.len() - full_rounds;
 
-    let mut new_state = MaybeUninit::<State>::uninit();
+    let mut new_state = MaybeUninit::<[Fr; W]>::uninit();
 
-    let mut apply_mds = |state: &mut State| {
+    let mut apply_mds = |state: &mut [Fr; W]| {
         fill_state(&mut new_state, &state[0]);
 
         let new_state = unsafe { new_state.assume_init_mut() };
 
         // Matrix multiplication
-        for i in 0..T {
-            new_state[i].mul_assign(&MDS[i][0]);
-            for j in 1..T {
-                mul_add_assign(&mut new_state[i], &state[j], &MDS[i][j]);
+        for i in 0..W {
+            new_state[i].mul_assign(&mds[i][0]);
+            for j in 1..W {
+                mul_add_assign(&mut new_state[i], &state[j], &mds[i][j]);
             }
         }
 
         set_state(state, new_state);
     };
 
-    for i in 0..R_F {
-        full_round(state, &ROUND_CONSTANTS[i], &mut apply_mds);
+    for rcs in &round_constants[..r_f] {
+        full_round(state, rcs, &mut apply_mds);
+    }
+    for rcs in &round_constants[r_f..r_f + r_p] {
+        partial_round(state, rcs, &mut apply_mds);
+    }
+    for rcs in &round_constants[r_f + r_p..] {
+        full_round(state, rcs, &mut apply_mds);
+    }
+}
+
+/// Precomputed sparse form of the partial rounds, see [`permute_sparse`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Sparse<'a, const W: usize> {
+    /// Replaces the MDS of the last full round before the partial rounds.
+    pub(crate) pre_mds: &'a [[Fr; W]; W],
+    /// Added once before the partial rounds, replaces their round constants.
+    pub(crate) pre_constants: &'a [Fr; W],
+    /// Added to `state[0]` after the S-box of each partial round.
+    pub(crate) partial_constants: &'a [Fr],
+    /// First row and first column of each partial round matrix, which is
+    /// the identity elsewhere.
+    pub(crate) first_rows: &'a [[Fr; W]],
+    pub(crate) first_columns: &'a [[Fr; W]],
+}
+
+/// Same permutation as [`permute_with`] with `2W - 1` multiplications per
+/// partial round instead of `W^2`.
+///
+/// Backwards from the last partial round, the constants added to
+/// `state[1..]` are moved through the MDS into the previous round, leaving
+/// a single constant on `state[0]`. The MDS of each partial round is then
+/// split into a sparse matrix times `diag(1, M')`, and `diag(1, M')`, which
+/// commutes with the partial S-box, is merged into the previous round's
+/// matrix. What is left of the first partial round ends up in `pre_mds` and
+/// `pre_constants`.
+///
+/// A partial round is one multiplication and `2W - 2` multiply-adds, where
+/// the dense matrix takes `W` multiplications and `W(W - 1)` multiply-adds.
+#[inline(always)]
+pub(crate) fn permute_sparse<const W: usize>(
+    state: &mut [Fr; W],
+    round_constants: &[[Fr; W]],
+    mds: &[[Fr; W]; W],
+    full_rounds: usize,
+    sparse: &Sparse<'_, W>,
+) {
+    let r_f = full_rounds / 2;
+    let r_p = round_constants.len() - full_rounds;
+
+    let mut new_state = MaybeUninit::<[Fr; W]>::uninit();
+
+    let mut apply_matrix = |state: &mut [Fr; W], matrix: &[[Fr; W]; W]| {
+        fill_state(&mut new_state, &state[0]);
+
+        let new_state = unsafe { new_state.assume_init_mut() };
+
+        for i in 0..W {
+            new_state[i].mul_assign(&matrix[i][0]);
+            for j in 1..W {
+                mul_add_assign(&mut new_state[i], &state[j], &matrix[i][j]);
+            }
+        }
+
+        set_state(state, new_state);
+    };
+
+    for rcs in &round_constants[..r_f - 1] {
+        full_round(state, rcs, |state: &mut [Fr; W]| apply_matrix(state, mds));
+    }
+    full_round(state, &round_constants[r_f - 1], |state: &mut [Fr; W]| {
+        apply_matrix(state, sparse.pre_mds)
+    });
+
+    for (word, c) in state.iter_mut().zip(sparse.pre_constants.iter()) {
+        word.add_assign(c);
     }
-    for i in R_F..R_F + R_P {
-        partial_round(state, &ROUND_CONSTANTS[i], &mut apply_mds);
+    for ((c, row), column) in sparse
+        .partial_constants
+        .iter()
+        .zip(sparse.first_rows)
+        .zip(sparse.first_columns)
+    {
+        sbox_inplace(&mut state[0]);
+        state[0].add_assign(c);
+
+        let first = state[0];
+        let mut new_first = first;
+        new_first.mul_assign(&row[0]);
+        for j in 1..W {
+            mul_add_assign(&mut new_first, &state[j], &row[j]);
+        }
+        for i in 1..W {
+            mul_add_assign(&mut state[i], &first, &column[i]);
+        }
+        state[0] = new_first;
+    }
+
+    for rcs in &round_constants[r_f + r_p..] {
+        full_round(state, rcs, |state: &mut [Fr; W]| apply_matrix(state, mds));
+    }
+}
+
+/// Poseidon2 permutation of width `W`: an initial external layer, then
+/// half of the full rounds, the partial rounds and the other half.
+#[inline(always)]
+pub(crate) fn permute2_with<const W: usize>(
+    state: &mut [Fr; W],
+    external_round_constants: &[[Fr; W]],
+    internal_round_constants: &[Fr],
+    internal_diag: &[Fr; W],
+) {
+    let r_f = external_round_constants.len() / 2;
+
+    let mut new_state = MaybeUninit::<[Fr; W]>::uninit();
+
+    external_layer(state);
+    for rcs in &external_round_constants[..r_f] {
+        for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+            word.add_assign(rc);
+            sbox_inplace(word);
+        }
+        external_layer(state);
+    }
+    for rc in internal_round_constants {
+        state[0].add_assign(rc);
+        sbox_inplace(&mut state[0]);
+        internal_layer(state, internal_diag, &mut new_state);
     }
-    for i in R_F + R_P..FULL_ROUNDS + PARTIAL_ROUNDS {
-        full_round(state, &ROUND_CONSTANTS[i], &mut apply_mds);
+    for rcs in &external_round_constants[r_f..] {
+        for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+            word.add_assign(rc);
+            sbox_inplace(word);
+        }
+        external_layer(state);
+    }
+}
+
+/// `circ(2, 1)` and `circ(2, 1, 1)` for widths 2 and 3, `M4` for width 4 and
+/// `circ(2 M4, M4, .., M4)` for larger multiples of 4. Additions only.
+#[inline(always)]
+fn external_layer<const W: usize>(state: &mut [Fr; W]) {
+    match W {
+        2 | 3 => {
+            let mut sum = state[0];
+            for word in &state[1..] {
+                sum.add_assign(word);
+            }
+            for word in state.iter_mut() {
+                word.add_assign(&sum);
+            }
+        }
+        4 => m4(state),
+        _ => {
+            for chunk in state.chunks_exact_mut(4) {
+                m4(chunk);
+            }
+            let mut sums = [Fr::zero(); 4];
+            for chunk in state.chunks_exact(4) {
+                for (sum, word) in sums.iter_mut().zip(chunk) {
+                    sum.add_assign(word);
+                }
+            }
+            for chunk in state.chunks_exact_mut(4) {
+                for (word, sum) in chunk.iter_mut().zip(sums.iter()) {
+                    word.add_assign(sum);
+                }
+            }
+        }
     }
 }
 
+/// `M4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]` with the
+/// addition chain of the Poseidon2 paper.
 #[inline(always)]
-fn full_round(state: &mut State, rcs: &[Fr; T], mut apply_mds: impl FnMut(&mut State)) {
+fn m4(x: &mut [Fr]) {
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = x[1].double() + t1;
+    let t3 = x[3].double() + t0;
+    let t4 = t1.double().double() + t3;
+    let t5 = t0.double().double() + t2;
+    x[0] = t3 + t5;
+    x[1] = t5;
+    x[2] = t2 + t4;
+    x[3] = t4;
+}
+
+/// `state[i] * diag[i] + sum(state)`, one multiplication per word.
+#[inline(always)]
+fn internal_layer<const W: usize>(
+    state: &mut [Fr; W],
+    diag: &[Fr; W],
+    new_state: &mut MaybeUninit<[Fr; W]>,
+) {
+    let mut sum = state[0];
+    for word in &state[1..] {
+        sum.add_assign(word);
+    }
+    fill_state(new_state, &sum);
+
+    let new_state = unsafe { new_state.assume_init_mut() };
+    for i in 0..W {
+        mul_add_assign(&mut new_state[i], &state[i], &diag[i]);
+    }
+
+    set_state(state, new_state);
+}
+
+#[inline(always)]
+fn full_round<const W: usize>(
+    state: &mut [Fr; W],
+    rcs: &[Fr; W],
+    mut apply_mds: impl FnMut(&mut [Fr; W]),
+) {
     for (word, rc) in state.iter_mut().zip(rcs.iter()) {
         word.add_assign(rc);
         sbox_inplace(word);
@@ -60,7 +274,11 @@ fn full_round(state: &mut State, rcs: &[Fr; T], mut apply_mds: impl FnMut(&mut S
 }
 
 #[inline(always)]
-fn partial_round(state: &mut State, rcs: &[Fr; T], mut apply_mds: impl FnMut(&mut State)) {
+fn partial_round<const W: usize>(
+    state: &mut [Fr; W],
+    rcs: &[Fr; W],
+    mut apply_mds: impl FnMut(&mut [Fr; W]),
+) {
     for (word, rc) in state.iter_mut().zip(rcs.iter()) {
         word.add_assign(rc);
     }
@@ -68,3 +286,174 @@ fn partial_round(state: &mut State, rcs: &[Fr; T], mut apply_mds: impl FnMut(&mu
     sbox_inplace(&mut state[0]);
     apply_mds(state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{widths, Field, Poseidon};
+
+    /// Owned [`Sparse`] constants.
+    struct SparseOwned<const W: usize> {
+        pre_mds: [[Fr; W]; W],
+        pre_constants: [Fr; W],
+        partial_constants: Vec<Fr>,
+        first_rows: Vec<[Fr; W]>,
+        first_columns: Vec<[Fr; W]>,
+    }
+
+    impl<const W: usize> SparseOwned<W> {
+        fn as_sparse(&self) -> Sparse<'_, W> {
+            Sparse {
+                pre_mds: &self.pre_mds,
+                pre_constants: &self.pre_constants,
+                partial_constants: &self.partial_constants,
+                first_rows: &self.first_rows,
+                first_columns: &self.first_columns,
+            }
+        }
+    }
+
+    fn invert(mut m: Vec<Vec<Fr>>) -> Vec<Vec<Fr>> {
+        let n = m.len();
+        for (i, row) in m.iter_mut().enumerate() {
+            row.extend((0..n).map(|j| if i == j { Fr::one() } else { Fr::zero() }));
+        }
+        for col in 0..n {
+            let pivot = (col..n).find(|i| !m[*i][col].is_zero_vartime()).unwrap();
+            m.swap(col, pivot);
+            let inv = m[col][col].invert().unwrap();
+            m[col].iter_mut().for_each(|v| *v *= inv);
+            for i in 0..n {
+                if i != col {
+                    let factor = m[i][col];
+                    let pivot_row = m[col].clone();
+                    m[i].iter_mut()
+                        .zip(pivot_row)
+                        .for_each(|(v, p)| *v -= factor * p);
+                }
+            }
+        }
+        m.into_iter().map(|row| row[n..].to_vec()).collect()
+    }
+
+    fn mat_vec<const W: usize>(m: &[[Fr; W]; W], v: &[Fr; W]) -> [Fr; W] {
+        std::array::from_fn(|i| (0..W).map(|j| m[i][j] * v[j]).sum())
+    }
+
+    /// Derives the sparse constants as described on [`permute_sparse`].
+    fn derive_sparse<const W: usize>(
+        round_constants: &[[Fr; W]],
+        mds: &[[Fr; W]; W],
+        full_rounds: usize,
+    ) -> SparseOwned<W> {
+        let r_f = full_rounds / 2;
+        let r_p = round_constants.len() - full_rounds;
+        let mds_inv = invert(mds.iter().map(|row| row.to_vec()).collect());
+        let mds_inv: [[Fr; W]; W] = std::array::from_fn(|i| std::array::from_fn(|j| mds_inv[i][j]));
+
+        // c_r + M x = M (x + M^-1 c_r): keep word 0 after the previous S-box,
+        // move the rest before it
+        let mut constants = round_constants[r_f..r_f + r_p].to_vec();
+        let mut partial_constants = vec![Fr::zero(); r_p];
+        for r in (1..r_p).rev() {
+            let moved = mat_vec(&mds_inv, &constants[r]);
+            partial_constants[r - 1] = moved[0];
+            for j in 1..W {
+                constants[r - 1][j] += moved[j];
+            }
+        }
+
+        // M_r = S_r diag(1, M'), diag(1, M') moves into the previous round
+        let mut first_rows = vec![[Fr::zero(); W]; r_p];
+        let mut first_columns = vec![[Fr::zero(); W]; r_p];
+        let mut matrix = *mds;
+        let mut diag = [[Fr::zero(); W]; W];
+        for r in (0..r_p).rev() {
+            let minor_inv = invert(matrix[1..].iter().map(|row| row[1..].to_vec()).collect());
+            first_rows[r][0] = matrix[0][0];
+            for j in 1..W {
+                first_rows[r][j] = (1..W).map(|k| matrix[0][k] * minor_inv[k - 1][j - 1]).sum();
+                first_columns[r][j] = matrix[j][0];
+            }
+            first_columns[r][0] = matrix[0][0];
+
+            diag = [[Fr::zero(); W]; W];
+            diag[0][0] = Fr::one();
+            for i in 1..W {
+                diag[i][1..].copy_from_slice(&matrix[i][1..]);
+            }
+            matrix = std::array::from_fn(|i| {
+                std::array::from_fn(|j| (0..W).map(|k| diag[i][k] * mds[k][j]).sum())
+            });
+        }
+
+        SparseOwned {
+            pre_mds: matrix,
+            pre_constants: mat_vec(&diag, &constants[0]),
+            partial_constants,
+            first_rows,
+            first_columns,
+        }
+    }
+
+    /// Deterministic, well spread test states.
+    fn states<const W: usize>(count: usize) -> impl Iterator<Item = [Fr; W]> {
+        let mut seed = Fr::from(0x5eed_u64);
+        let edges = [[Fr::zero(); W], [Fr::one(); W], [-Fr::one(); W]];
+        edges.into_iter().chain((0..count).map(move |_| {
+            std::array::from_fn(|_| {
+                seed = seed.square() + Fr::from(7u64);
+                seed
+            })
+        }))
+    }
+
+    #[test]
+    fn test_sparse_constants() {
+        let derived = derive_sparse(&ROUND_CONSTANTS, &MDS, FULL_ROUNDS);
+        assert_eq!(derived.pre_mds, SPARSE_PRE_MDS);
+        assert_eq!(derived.pre_constants, SPARSE_PRE_CONSTANTS);
+        assert_eq!(derived.partial_constants, SPARSE_PARTIAL_CONSTANTS);
+        assert_eq!(derived.first_rows, SPARSE_FIRST_ROWS);
+        assert_eq!(derived.first_columns, SPARSE_FIRST_COLUMNS);
+    }
+
+    #[test]
+    fn test_sparse_permutation() {
+        let dense = Poseidon::<3, 8, 57>::new(&ROUND_CONSTANTS, &MDS);
+        for state in states::<3>(2000) {
+            let mut expected = state;
+            dense.permute(&mut expected);
+
+            let mut sparse = state;
+            permute(&mut sparse);
+            assert_eq!(sparse, expected);
+        }
+    }
+
+    #[test]
+    fn test_sparse_widths() {
+        macro_rules! check {
+            ($($t:ident),*) => {$(
+                let poseidon = &widths::$t::POSEIDON;
+                let sparse = derive_sparse(poseidon.round_constants(), poseidon.mds(), widths::$t::FULL_ROUNDS);
+                for state in states::<{ widths::$t::T }>(20) {
+                    let mut expected = state;
+                    poseidon.permute(&mut expected);
+
+                    let mut state = state;
+                    permute_sparse(
+                        &mut state,
+                        poseidon.round_constants(),
+                        poseidon.mds(),
+                        widths::$t::FULL_ROUNDS,
+                        &sparse.as_sparse(),
+                    );
+                    assert_eq!(state, expected);
+                }
+            )*};
+        }
+        check!(t2, t4, t5, t6, t7, t8, t9, t10, t11, t12, t13);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Fr, State, T};
+use crate::Fr;
 use sp1_intrinsics::{
     bn254::syscall_bn254_scalar_mac,
     memory::{memcpy32, memcpy64},
@@ -7,41 +7,30 @@ use std::mem::MaybeUninit;
 
 #[inline(always)]
 pub(crate) fn sbox_inplace(val: &mut Fr) {
-    let mut temp = MaybeUninit::<Fr>::uninit();
-    let zero = Fr::zero();
-    let mut temp2 = MaybeUninit::<Fr>::uninit();
-
-    let mut temp3 = MaybeUninit::<Fr>::uninit();
-
-    let mut temp4 = MaybeUninit::<Fr>::uninit();
-
-    let mut temp5 = MaybeUninit::<Fr>::uninit();
+    // the mac syscall accumulates into its destination, so it starts from zero
+    let mut square = Fr::zero();
+    let mut quad = Fr::zero();
+    let mut quint = Fr::zero();
+    // and its operands are copied so they never alias
+    let mut val_copy = MaybeUninit::<Fr>::uninit();
+    let mut square_copy = MaybeUninit::<Fr>::uninit();
     unsafe {
-        let ptr = temp.as_mut_ptr();
-        memcpy32(&zero, ptr);
-        let qtr = temp2.as_mut_ptr();
-        memcpy32(val, qtr);
-
-        let ttr = temp3.as_mut_ptr();
-        let utr = temp4.as_mut_ptr();
-        let vtr = temp5.as_mut_ptr();
-        memcpy32(&zero, utr);
-        memcpy32(&zero, vtr);
-        syscall_bn254_scalar_mac(ptr, val as *const Fr, qtr as *const Fr); // ptr = val * val (val^2)
+        memcpy32(val, val_copy.as_mut_ptr());
+        syscall_bn254_scalar_mac(&mut square, val, val_copy.as_ptr()); // val^2
 
-        memcpy32(ptr, ttr);
-        syscall_bn254_scalar_mac(utr, ptr as *const Fr, ttr as *const Fr); // utr = val^4
+        memcpy32(&square, square_copy.as_mut_ptr());
+        syscall_bn254_scalar_mac(&mut quad, &square, square_copy.as_ptr()); // val^4
 
-        syscall_bn254_scalar_mac(vtr, utr as *const Fr, val as *const Fr); // utr = val^4
+        syscall_bn254_scalar_mac(&mut quint, &quad, val); // val^5
 
-        memcpy32(vtr, val);
+        memcpy32(&quint, val);
     };
 }
 
 #[inline(always)]
-pub(crate) fn fill_state(state: &mut MaybeUninit<State>, val: &Fr) {
+pub(crate) fn fill_state<const W: usize>(state: &mut MaybeUninit<[Fr; W]>, val: &Fr) {
     let ptr = state.as_mut_ptr() as *mut Fr;
-    for i in 0..T {
+    for i in 0..W {
         unsafe {
             memcpy32(val, ptr.add(i));
         }
@@ -49,48 +38,47 @@ pub(crate) fn fill_state(state: &mut MaybeUninit<State>, val: &Fr) {
 }
 
 #[inline(always)]
-pub(crate) fn set_state(state: &mut State, new_state: &State) {
-    unsafe {
-        memcpy32(&new_state[0], &mut state[0]);
-        memcpy32(&new_state[1], &mut state[1]);
-        memcpy32(&new_state[2], &mut state[2]);
+pub(crate) fn set_state<const W: usize>(state: &mut [Fr; W], new_state: &[Fr; W]) {
+    for i in 0..W {
+        unsafe {
+            memcpy32(&new_state[i], &mut state[i]);
+        }
     }
 }
 
 #[inline(always)]
-pub(crate) fn init_state_with_cap_and_msg<'a>(
-    state: &'a mut MaybeUninit<State>,
+pub(crate) fn init_state_with_cap_and_msg<'a, const W: usize>(
+    state: &'a mut MaybeUninit<[Fr; W]>,
     cap: &Fr,
     msg: &[Fr],
-) -> &'a mut State {
+) -> &'a mut [Fr; W] {
     static ZERO_TWO: [Fr; 2] = [Fr::zero(), Fr::zero()];
 
+    let n = msg.len().min(W - 1);
     unsafe {
         let ptr = state.as_mut_ptr() as *mut Fr;
         memcpy32(cap, ptr);
-        match msg.len() {
-            0 => {
-                memcpy64(ZERO_TWO.as_ptr(), ptr.add(1));
-            }
-            1 => {
-                memcpy32(msg.as_ptr(), ptr.add(1));
-                memcpy32(ZERO_TWO.as_ptr(), ptr.add(2));
-            }
-            _ => {
-                memcpy64(msg.as_ptr(), ptr.add(1));
-            }
+        // copy the message, then zero the rest of the rate, two words at a time
+        let mut i = 0;
+        while i + 1 < n {
+            memcpy64(msg.as_ptr().add(i), ptr.add(1 + i));
+            i += 2;
+        }
+        if i < n {
+            memcpy32(msg.as_ptr().add(i), ptr.add(1 + i));
+            i += 1;
+        }
+        while i + 1 < W - 1 {
+            memcpy64(ZERO_TWO.as_ptr(), ptr.add(1 + i));
+            i += 2;
+        }
+        if i < W - 1 {
+            memcpy32(ZERO_TWO.as_ptr(), ptr.add(1 + i));
         }
         state.assume_init_mut()
     }
 }
 
-#[inline(always)]
-pub(crate) unsafe fn set_fr(dst: *mut Fr, val: &Fr) {
-    unsafe {
-        memcpy32(val, dst);
-    }
-}
-
 #[inline(always)]
 pub(crate) fn mul_add_assign(dst: &mut Fr, a: &Fr, b: &Fr) {
     unsafe {
@@ -2,30 +2,56 @@
 #![allow(clippy::op_ref)]
 #![allow(unexpected_cfgs)]
 
-use itertools::Itertools;
-use std::mem::MaybeUninit;
-use std::ops::AddAssign;
-
 pub use bn254::{
     ff::{Field, PrimeField},
     Fr,
 };
 
 mod constants;
+pub mod grain;
+mod hasher;
+mod hint;
 mod imp;
+mod poseidon;
+pub mod poseidon2;
+pub mod security;
+mod sponge;
+pub mod widths;
+mod xof;
+pub mod zktrie;
+#[cfg(feature = "zkvm-hint")]
+mod zkvm_hints;
+
+#[cfg(all(target_os = "zkvm", feature = "zkvm-hint"))]
+pub use zkvm_hints::set_hint_source;
+#[cfg(feature = "zkvm-hint-verify")]
+pub use zkvm_hints::verify_pending_hints;
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct", feature = "zkvm-hint"))]
+pub use zkvm_hints::Sp1HintSource;
 #[cfg(all(
     not(target_os = "zkvm"),
     not(target_vendor = "succinct"),
-    feature = "zkvm-hint"
+    feature = "zkvm-hint-replay"
 ))]
-mod zkvm_hints;
-
+pub use zkvm_hints::{replay_hints, HintDivergence, ReplayReport};
 #[cfg(all(
     not(target_os = "zkvm"),
     not(target_vendor = "succinct"),
     feature = "zkvm-hint"
 ))]
-pub use zkvm_hints::set_zkvm_hint_hook;
+pub use zkvm_hints::{
+    set_zkvm_hint_event_hook, set_zkvm_hint_hook, set_zkvm_hint_record_hook, with_hint_sink,
+};
+#[cfg(feature = "zkvm-hint")]
+pub use zkvm_hints::{HintEvent, HintRecord, HintSource, HintTape, MemoryHintSource};
+
+pub use hasher::{hash_code_reader, CodeHasher, MsgHasher};
+pub use hint::{HintError, HintKind};
+pub use poseidon::Poseidon;
+pub use poseidon2::Poseidon2;
+pub use sponge::{IoPattern, Sponge, SpongeError, SpongeOp};
+pub use widths::t3::POSEIDON;
+pub use xof::{hash_msg_xof, hash_with_domain_xof, Xof};
 
 pub(crate) use constants::*;
 
@@ -33,126 +59,187 @@ pub(crate) type State = [Fr; T];
 pub(crate) type Mds = [[Fr; T]; T];
 
 pub fn hash_with_domain(inp: &[Fr; 2], domain: Fr) -> Fr {
-    #[cfg(all(target_os = "zkvm", target_vendor = "succinct", feature = "zkvm-hint"))]
-    return Fr::from_repr_vartime(sp1_lib::io::read_vec().try_into().unwrap()).unwrap();
+    try_hash_with_domain(inp, domain).unwrap_or_else(|err| panic!("{err}"))
+}
 
+/// Like [`hash_with_domain`], but a malformed zkvm hint is returned as an
+/// error. Only the guest and a host replay read hints, it never fails
+/// otherwise.
+pub fn try_hash_with_domain(inp: &[Fr; 2], domain: Fr) -> Result<Fr, HintError> {
     if inp[1].is_zero_vartime() && inp[0].is_zero_vartime() && domain.is_zero_vartime() {
-        return EMPTY_HASH;
+        return Ok(EMPTY_HASH);
+    }
+
+    #[cfg(all(target_os = "zkvm", feature = "zkvm-hint"))]
+    {
+        let (index, output) = zkvm_hints::read(HintKind::HashWithDomain, RATE)?;
+        let output = zkvm_hints::decode_fr(index, HintKind::HashWithDomain, RATE, output)?;
+        #[cfg(feature = "zkvm-hint-verify")]
+        zkvm_hints::verify_hint(
+            index,
+            zkvm_hints::PendingHint::HashWithDomain {
+                inp: *inp,
+                domain,
+                output,
+            },
+        );
+        return Ok(output);
     }
-    let mut state = MaybeUninit::<State>::uninit();
-    let state = imp::init_state_with_cap_and_msg(&mut state, &domain, inp);
-    imp::permute(state);
+
+    #[cfg(all(
+        not(target_os = "zkvm"),
+        not(target_vendor = "succinct"),
+        feature = "zkvm-hint-replay"
+    ))]
+    if let Some(hint) = zkvm_hints::replay(HintKind::HashWithDomain, RATE, || {
+        POSEIDON.hash_with_domain(inp, domain).to_repr()
+    }) {
+        let (index, output) = hint?;
+        return zkvm_hints::decode_fr(index, HintKind::HashWithDomain, RATE, output);
+    }
+
+    let hash = POSEIDON.hash_with_domain(inp, domain);
 
     #[cfg(all(
         not(target_os = "zkvm"),
         not(target_vendor = "succinct"),
         feature = "zkvm-hint"
     ))]
-    zkvm_hints::hint(state[0].to_repr());
+    zkvm_hints::hint(HintEvent::HashWithDomain {
+        inp,
+        domain,
+        output: hash,
+    });
 
-    state[0]
+    Ok(hash)
 }
 
 pub fn hash_msg(msg: &[Fr], cap: Option<u128>) -> Fr {
-    debug_assert_eq!(RATE, 2);
+    try_hash_msg(msg, cap).unwrap_or_else(|err| panic!("{err}"))
+}
 
+/// Like [`hash_msg`], see [`try_hash_with_domain`].
+pub fn try_hash_msg(msg: &[Fr], cap: Option<u128>) -> Result<Fr, HintError> {
     if msg.is_empty() && cap.map(|c| c == 0).unwrap_or(true) {
-        return EMPTY_HASH;
+        return Ok(EMPTY_HASH);
     }
 
-    #[cfg(all(target_os = "zkvm", target_vendor = "succinct", feature = "zkvm-hint"))]
-    return Fr::from_repr_vartime(sp1_lib::io::read_vec().try_into().unwrap()).unwrap();
-
-    let cap = cap.map(Fr::from_u128).unwrap_or_else(|| {
-        // trick here since msg.len() won't exceed u64::MAX
-        // msg.len() * (1 << 64) = msg.len() << 64
-        Fr::from_raw([0, msg.len() as u64, 0, 0])
-    });
-
-    let mut state = MaybeUninit::<State>::uninit();
+    #[cfg(all(target_os = "zkvm", feature = "zkvm-hint"))]
+    {
+        let (index, output) = zkvm_hints::read(HintKind::HashMsg, msg.len())?;
+        let output = zkvm_hints::decode_fr(index, HintKind::HashMsg, msg.len(), output)?;
+        #[cfg(feature = "zkvm-hint-verify")]
+        zkvm_hints::verify_hint(
+            index,
+            zkvm_hints::PendingHint::HashMsg {
+                msg: msg.into(),
+                cap,
+                output,
+            },
+        );
+        return Ok(output);
+    }
 
-    let state = imp::init_state_with_cap_and_msg(&mut state, &cap, msg);
-    imp::permute(state);
+    #[cfg(all(
+        not(target_os = "zkvm"),
+        not(target_vendor = "succinct"),
+        feature = "zkvm-hint-replay"
+    ))]
+    if let Some(hint) = zkvm_hints::replay(HintKind::HashMsg, msg.len(), || {
+        POSEIDON.hash_msg(msg, cap).to_repr()
+    }) {
+        let (index, output) = hint?;
+        return zkvm_hints::decode_fr(index, HintKind::HashMsg, msg.len(), output);
+    }
 
-    if msg.len() > 2 {
-        for chunk in msg.chunks(RATE).skip(1) {
-            if chunk.len() == RATE {
-                state[1].add_assign(&chunk[0]);
-                state[2].add_assign(&chunk[1]);
-                imp::permute(state);
-            } else {
-                state[1].add_assign(&chunk[0]);
-                imp::permute(state);
-            }
-        }
-    };
+    let hash = POSEIDON.hash_msg(msg, cap);
 
     #[cfg(all(
         not(target_os = "zkvm"),
         not(target_vendor = "succinct"),
         feature = "zkvm-hint"
     ))]
-    zkvm_hints::hint(state[0].to_repr());
+    zkvm_hints::hint(HintEvent::HashMsg {
+        msg,
+        cap,
+        output: hash,
+    });
 
-    state[0]
+    Ok(hash)
 }
 
 pub fn hash_code(code: &[u8]) -> [u8; 32] {
+    try_hash_code(code).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Like [`hash_code`], see [`try_hash_with_domain`].
+pub fn try_hash_code(code: &[u8]) -> Result<[u8; 32], HintError> {
     if code.is_empty() {
-        return EMPTY_HASH_BYTES;
+        return Ok(EMPTY_HASH_BYTES);
     }
 
-    #[cfg(all(target_os = "zkvm", target_vendor = "succinct", feature = "zkvm-hint"))]
-    return sp1_lib::io::read_vec().try_into().unwrap();
+    #[cfg(all(target_os = "zkvm", feature = "zkvm-hint"))]
+    {
+        let (_index, output) = zkvm_hints::read(HintKind::HashCode, code.len())?;
+        #[cfg(feature = "zkvm-hint-verify")]
+        zkvm_hints::verify_hint(
+            _index,
+            zkvm_hints::PendingHint::HashCode {
+                code: code.into(),
+                output,
+            },
+        );
+        return Ok(output);
+    }
 
-    let mut msg = code.chunks(POSEIDON_HASH_BYTES_IN_FIELD).map(|chunk| {
-        let mut be_bytes = [0u8; 32];
-        be_bytes[1..1 + chunk.len()].copy_from_slice(chunk);
-        be_bytes.reverse();
-        Fr::from_bytes(&be_bytes).unwrap()
+    #[cfg(all(
+        not(target_os = "zkvm"),
+        not(target_vendor = "succinct"),
+        feature = "zkvm-hint-replay"
+    ))]
+    if let Some(hint) =
+        zkvm_hints::replay(HintKind::HashCode, code.len(), || compute_code_hash(code))
+    {
+        return hint.map(|(_, output)| output);
+    }
+
+    let result = compute_code_hash(code);
+
+    #[cfg(all(
+        not(target_os = "zkvm"),
+        not(target_vendor = "succinct"),
+        feature = "zkvm-hint"
+    ))]
+    zkvm_hints::hint(HintEvent::HashCode {
+        code_len: code.len(),
+        output: result,
     });
 
+    Ok(result)
+}
+
+#[inline(always)]
+fn compute_code_hash(code: &[u8]) -> [u8; 32] {
+    let msg = code
+        .chunks(POSEIDON_HASH_BYTES_IN_FIELD)
+        .map(code_chunk_to_fr);
     let cap = Fr::from_raw([0, code.len() as u64, 0, 0]);
 
-    let mut bytes = match msg.len() {
-        // Safety: we know that the iterator is not empty
-        0 => unsafe { std::hint::unreachable_unchecked() },
-        1 => {
-            let hash =
-                hash_with_domain(&[unsafe { msg.next().unwrap_unchecked() }, Fr::zero()], cap);
-            hash.to_repr()
-        }
-        _ => {
-            let mut state = MaybeUninit::<State>::uninit();
-            let state = unsafe {
-                imp::set_fr(state.as_mut_ptr() as *mut Fr, &cap);
-                imp::set_fr(
-                    (state.as_mut_ptr() as *mut Fr).add(1),
-                    &msg.next().unwrap_unchecked(),
-                );
-                imp::set_fr(
-                    (state.as_mut_ptr() as *mut Fr).add(2),
-                    &msg.next().unwrap_unchecked(),
-                );
-                state.assume_init_mut()
-            };
-            imp::permute(state);
-
-            for mut chunk in msg.chunks(RATE).into_iter() {
-                let a = chunk.next().unwrap();
-                if let Some(b) = chunk.next() {
-                    state[1].add_assign(a);
-                    state[2].add_assign(b);
-                    imp::permute(state);
-                } else {
-                    state[1].add_assign(a);
-                    imp::permute(state);
-                }
-            }
-            state[0].to_repr()
-        }
-    };
+    code_hash_to_bytes(&mut POSEIDON.hash_iter(cap, msg).to_repr())
+}
+
+/// Packs up to [`POSEIDON_HASH_BYTES_IN_FIELD`] big-endian code bytes into a field element.
+#[inline(always)]
+pub(crate) fn code_chunk_to_fr(chunk: &[u8]) -> Fr {
+    let mut be_bytes = [0u8; 32];
+    be_bytes[1..1 + chunk.len()].copy_from_slice(chunk);
+    be_bytes.reverse();
+    Fr::from_bytes(&be_bytes).unwrap()
+}
 
+/// Reorders the little-endian repr of a code hash into the big-endian limb layout.
+#[inline(always)]
+pub(crate) fn code_hash_to_bytes(bytes: &mut [u8; 32]) -> [u8; 32] {
     bytes[0..8].reverse();
     bytes[8..16].reverse();
     bytes[16..24].reverse();
@@ -162,14 +249,6 @@ pub fn hash_code(code: &[u8]) -> [u8; 32] {
     result[16..24].copy_from_slice(&bytes[8..16]);
     result[8..16].copy_from_slice(&bytes[16..24]);
     result[0..8].copy_from_slice(&bytes[24..32]);
-
-    #[cfg(all(
-        not(target_os = "zkvm"),
-        not(target_vendor = "succinct"),
-        feature = "zkvm-hint"
-    ))]
-    zkvm_hints::hint(result);
-
     result
 }
 
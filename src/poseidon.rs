@@ -0,0 +1,189 @@
+use crate::{imp, Fr, PrimeField};
+use std::mem::MaybeUninit;
+use std::ops::AddAssign;
+
+/// Poseidon permutation of width `T` with `RF` full and `RP` partial rounds,
+/// over borrowed round constants and MDS matrix.
+///
+/// The crate's own parameter sets are instances of this type, see
+/// [`widths`](crate::widths). Other audited constants can be plugged in with
+/// [`Poseidon::new`], everything still runs on the host or SP1 backend.
+#[derive(Clone, Copy, Debug)]
+pub struct Poseidon<'a, const T: usize, const RF: usize, const RP: usize> {
+    round_constants: &'a [[Fr; T]],
+    mds: &'a [[Fr; T]; T],
+    sparse: Option<imp::Sparse<'a, T>>,
+}
+
+impl<'a, const T: usize, const RF: usize, const RP: usize> Poseidon<'a, T, RF, RP> {
+    pub const RATE: usize = T - 1;
+
+    /// `round_constants` must hold one row per round, `RF + RP` in total.
+    pub const fn new(round_constants: &'a [[Fr; T]], mds: &'a [[Fr; T]; T]) -> Self {
+        assert!(T >= 2, "width must be at least 2");
+        assert!(RF & 1 == 0, "full rounds must be even");
+        assert!(
+            round_constants.len() == RF + RP,
+            "expected one row of round constants per round"
+        );
+        Self {
+            round_constants,
+            mds,
+            sparse: None,
+        }
+    }
+
+    /// Precomputed sparse partial rounds for the same constants.
+    pub(crate) const fn with_sparse(self, sparse: imp::Sparse<'a, T>) -> Self {
+        assert!(
+            sparse.partial_constants.len() == RP
+                && sparse.first_rows.len() == RP
+                && sparse.first_columns.len() == RP,
+            "expected sparse constants for every partial round"
+        );
+        Self {
+            sparse: Some(sparse),
+            ..self
+        }
+    }
+
+    pub fn round_constants(&self) -> &'a [[Fr; T]] {
+        self.round_constants
+    }
+
+    pub fn mds(&self) -> &'a [[Fr; T]; T] {
+        self.mds
+    }
+
+    #[inline(always)]
+    pub fn permute(&self, state: &mut [Fr; T]) {
+        match &self.sparse {
+            Some(sparse) => imp::permute_sparse(state, self.round_constants, self.mds, RF, sparse),
+            None => imp::permute_with(state, self.round_constants, self.mds, RF),
+        }
+    }
+
+    /// Hashes `T - 1` elements with `domain` as capacity, returning `state[0]`.
+    #[inline(always)]
+    pub fn hash_with_domain<const R: usize>(&self, inp: &[Fr; R], domain: Fr) -> Fr {
+        sponge_hash_with_domain(inp, domain, |state| self.permute(state))
+    }
+
+    /// Sponge hash of `msg` absorbing `T - 1` elements per permutation.
+    ///
+    /// Without `cap`, the capacity is `msg.len() << 64`.
+    #[inline(always)]
+    pub fn hash_msg(&self, msg: &[Fr], cap: Option<u128>) -> Fr {
+        sponge_hash_msg(msg, cap, |state| self.permute(state))
+    }
+
+    /// Same sponge as [`hash_msg`](Self::hash_msg) with an explicit capacity,
+    /// over elements produced on the fly.
+    #[inline(always)]
+    pub fn hash_iter(&self, cap: Fr, msg: impl IntoIterator<Item = Fr>) -> Fr {
+        let mut msg = msg.into_iter();
+        let mut state = [Fr::zero(); T];
+        state[0] = cap;
+
+        // the first block is permuted even if empty
+        let mut first = true;
+        loop {
+            let mut absorbed = 0;
+            for (word, elem) in state[1..].iter_mut().zip(msg.by_ref()) {
+                word.add_assign(&elem);
+                absorbed += 1;
+            }
+            if absorbed == 0 && !first {
+                break;
+            }
+            self.permute(&mut state);
+            if absorbed < Self::RATE {
+                break;
+            }
+            first = false;
+        }
+        state[0]
+    }
+}
+
+/// [`Poseidon::hash_with_domain`] over any permutation of width `T`.
+#[inline(always)]
+pub(crate) fn sponge_hash_with_domain<const T: usize, const R: usize>(
+    inp: &[Fr; R],
+    domain: Fr,
+    permute: impl Fn(&mut [Fr; T]),
+) -> Fr {
+    assert_eq!(R + 1, T, "input must fill the rate");
+    let mut state = MaybeUninit::<[Fr; T]>::uninit();
+    let state = imp::init_state_with_cap_and_msg(&mut state, &domain, inp);
+    permute(state);
+    state[0]
+}
+
+/// [`Poseidon::hash_msg`] over any permutation of width `T`.
+#[inline(always)]
+pub(crate) fn sponge_hash_msg<const T: usize>(
+    msg: &[Fr],
+    cap: Option<u128>,
+    permute: impl Fn(&mut [Fr; T]),
+) -> Fr {
+    let rate = T - 1;
+    let cap = cap.map(Fr::from_u128).unwrap_or_else(|| {
+        // trick here since msg.len() won't exceed u64::MAX
+        // msg.len() * (1 << 64) = msg.len() << 64
+        Fr::from_raw([0, msg.len() as u64, 0, 0])
+    });
+
+    let mut state = MaybeUninit::<[Fr; T]>::uninit();
+    let state = imp::init_state_with_cap_and_msg(&mut state, &cap, msg);
+    permute(state);
+
+    if msg.len() > rate {
+        for chunk in msg[rate..].chunks(rate) {
+            for (word, elem) in state[1..].iter_mut().zip(chunk) {
+                word.add_assign(elem);
+            }
+            permute(state);
+        }
+    }
+    state[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_msg, hash_with_domain, widths, MDS, ROUND_CONSTANTS};
+
+    #[test]
+    fn test_custom_instance() {
+        // an instance over borrowed constants behaves like the built-in one
+        let poseidon = Poseidon::<3, 8, 57>::new(&ROUND_CONSTANTS, &MDS);
+
+        let inp = [Fr::from(1u64), Fr::from(2u64)];
+        let domain = Fr::from(3u64);
+        assert_eq!(
+            poseidon.hash_with_domain(&inp, domain),
+            hash_with_domain(&inp, domain)
+        );
+
+        let msg: Vec<_> = (0..11u64).map(Fr::from).collect();
+        for len in 1..msg.len() {
+            let msg = &msg[..len];
+            assert_eq!(poseidon.hash_msg(msg, None), hash_msg(msg, None));
+            assert_eq!(poseidon.hash_msg(msg, Some(7)), hash_msg(msg, Some(7)));
+        }
+    }
+
+    #[test]
+    fn test_hash_iter() {
+        let poseidon = &widths::t5::POSEIDON;
+        let msg: Vec<_> = (0..13u64).map(Fr::from).collect();
+        for len in 0..msg.len() {
+            let msg = &msg[..len];
+            assert_eq!(
+                poseidon.hash_iter(Fr::from_u128(9), msg.iter().copied()),
+                poseidon.hash_msg(msg, Some(9))
+            );
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Poseidon2 permutation over BN254.
+//!
+//! Poseidon2 replaces the dense MDS of every round by a cheap external
+//! layer in the full rounds and a diagonal plus all-ones internal layer in
+//! the partial rounds, so a partial round costs `T` multiplications instead
+//! of `T^2`.
+//! The round constants come from the reference Grain LFSR with 8 full and
+//! 56 partial rounds.
+//!
+//! [`t3`] is the instance of the HorizenLabs reference implementation and
+//! [`t4`] the one of barretenberg (Noir's `poseidon2_permutation`), both
+//! checked against their test vectors. [`t2`] uses the reference `[[2, 1],
+//! [1, 3]]` internal matrix, there is no published test vector for it.
+//!
+//! The hash functions use the same sponge as [`crate::hash_msg`] and
+//! [`crate::hash_with_domain`], only the permutation differs.
+
+use crate::poseidon::{sponge_hash_msg, sponge_hash_with_domain};
+use crate::{imp, Fr};
+
+macro_rules! poseidon2_instance {
+    () => {
+        pub static POSEIDON2: Poseidon2<'static, T, FULL_ROUNDS, PARTIAL_ROUNDS> = Poseidon2::new(
+            &EXTERNAL_ROUND_CONSTANTS,
+            &INTERNAL_ROUND_CONSTANTS,
+            &INTERNAL_DIAG,
+        );
+
+        /// Like [`crate::hash_with_domain`], with the Poseidon2 permutation.
+        pub fn hash_with_domain(inp: &[Fr; RATE], domain: Fr) -> Fr {
+            POSEIDON2.hash_with_domain(inp, domain)
+        }
+
+        /// Like [`crate::hash_msg`], with the Poseidon2 permutation.
+        pub fn hash_msg(msg: &[Fr], cap: Option<u128>) -> Fr {
+            POSEIDON2.hash_msg(msg, cap)
+        }
+    };
+}
+
+pub mod t2;
+pub mod t3;
+pub mod t4;
+
+/// Poseidon2 permutation of width `T` with `RF` full and `RP` partial rounds,
+/// over borrowed round constants and internal matrix diagonal.
+///
+/// The external layer is fixed by the width, which must be 2, 3 or a
+/// multiple of 4.
+#[derive(Clone, Copy, Debug)]
+pub struct Poseidon2<'a, const T: usize, const RF: usize, const RP: usize> {
+    external_round_constants: &'a [[Fr; T]],
+    internal_round_constants: &'a [Fr],
+    internal_diag: &'a [Fr; T],
+}
+
+impl<'a, const T: usize, const RF: usize, const RP: usize> Poseidon2<'a, T, RF, RP> {
+    pub const RATE: usize = T - 1;
+
+    /// `external_round_constants` holds one row per full round, the first
+    /// `RF / 2` rows before the partial rounds; `internal_round_constants`
+    /// one constant per partial round.
+    pub const fn new(
+        external_round_constants: &'a [[Fr; T]],
+        internal_round_constants: &'a [Fr],
+        internal_diag: &'a [Fr; T],
+    ) -> Self {
+        assert!(
+            T == 2 || T == 3 || (T != 0 && T & 3 == 0),
+            "width must be 2, 3 or a multiple of 4"
+        );
+        assert!(RF & 1 == 0, "full rounds must be even");
+        assert!(
+            external_round_constants.len() == RF,
+            "expected one row of round constants per full round"
+        );
+        assert!(
+            internal_round_constants.len() == RP,
+            "expected one round constant per partial round"
+        );
+        Self {
+            external_round_constants,
+            internal_round_constants,
+            internal_diag,
+        }
+    }
+
+    pub fn external_round_constants(&self) -> &'a [[Fr; T]] {
+        self.external_round_constants
+    }
+
+    pub fn internal_round_constants(&self) -> &'a [Fr] {
+        self.internal_round_constants
+    }
+
+    pub fn internal_diag(&self) -> &'a [Fr; T] {
+        self.internal_diag
+    }
+
+    #[inline(always)]
+    pub fn permute(&self, state: &mut [Fr; T]) {
+        imp::permute2_with(
+            state,
+            self.external_round_constants,
+            self.internal_round_constants,
+            self.internal_diag,
+        );
+    }
+
+    /// Hashes `T - 1` elements with `domain` as capacity, returning `state[0]`.
+    #[inline(always)]
+    pub fn hash_with_domain<const R: usize>(&self, inp: &[Fr; R], domain: Fr) -> Fr {
+        sponge_hash_with_domain(inp, domain, |state| self.permute(state))
+    }
+
+    /// Sponge hash of `msg` absorbing `T - 1` elements per permutation.
+    ///
+    /// Without `cap`, the capacity is `msg.len() << 64`.
+    #[inline(always)]
+    pub fn hash_msg(&self, msg: &[Fr], cap: Option<u128>) -> Fr {
+        sponge_hash_msg(msg, cap, |state| self.permute(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grain::{generate_poseidon2, Sbox};
+    use crate::PrimeField;
+
+    fn fr(hex: &str) -> Fr {
+        let mut repr = [0u8; 32];
+        for (i, byte) in repr.iter_mut().enumerate() {
+            let pos = hex.len() - 2 * (i + 1);
+            *byte = u8::from_str_radix(&hex[pos..pos + 2], 16).unwrap();
+        }
+        Fr::from_repr_vartime(repr).unwrap()
+    }
+
+    fn counting<const T: usize>() -> [Fr; T] {
+        std::array::from_fn(|i| Fr::from(i as u64))
+    }
+
+    #[test]
+    fn test_vectors() {
+        // HorizenLabs poseidon2 `poseidon2_bn256` test
+        let mut state = counting::<3>();
+        t3::POSEIDON2.permute(&mut state);
+        assert_eq!(
+            state,
+            [
+                fr("0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033"),
+                fr("303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570"),
+                fr("1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8"),
+            ]
+        );
+
+        // barretenberg `poseidon2` permutation test
+        let mut state = counting::<4>();
+        t4::POSEIDON2.permute(&mut state);
+        assert_eq!(
+            state,
+            [
+                fr("01bd538c2ee014ed5141b29e9ae240bf8db3fe5b9a38629a9647cf8d76c01737"),
+                fr("239b62e7db98aa3a2a8f6a0d2fa1709e7a35959aa6c7034814d9daa90cbac662"),
+                fr("04cbb44c61d928ed06808456bf758cbf0c18d1e15a7b6dbc8245fa7515d5e3cb"),
+                fr("2e11c5cff2a22c64d01304b778d78f6998eff1ab73163a35603f54794c30847a"),
+            ]
+        );
+
+        // no published vector, computed with a port of the reference sage script
+        let mut state = counting::<2>();
+        t2::POSEIDON2.permute(&mut state);
+        assert_eq!(
+            state,
+            [
+                fr("1d01e56f49579cec72319e145f06f6177f6c5253206e78c2689781452a31878b"),
+                fr("0d189ec589c41b8cffa88cfc523618a055abe8192c70f75aa72fc514560f6c61"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reproduces_constants() {
+        macro_rules! check {
+            ($($t:ident),*) => {$(
+                let poseidon2 = &$t::POSEIDON2;
+                let constants = generate_poseidon2::<Fr, { $t::T }>(
+                    Sbox::Pow(5),
+                    $t::FULL_ROUNDS,
+                    $t::PARTIAL_ROUNDS,
+                );
+                assert_eq!(constants.external, poseidon2.external_round_constants());
+                assert_eq!(constants.internal, poseidon2.internal_round_constants());
+            )*};
+        }
+        check!(t2, t3, t4);
+    }
+
+    #[test]
+    fn test_sponge() {
+        let msg: Vec<_> = (1..8u64).map(Fr::from).collect();
+        let poseidon2 = &t3::POSEIDON2;
+
+        let mut state = [Fr::from(9u64), msg[0], msg[1]];
+        poseidon2.permute(&mut state);
+        assert_eq!(
+            t3::hash_with_domain(&[msg[0], msg[1]], Fr::from(9u64)),
+            state[0]
+        );
+
+        for len in 1..msg.len() {
+            let msg = &msg[..len];
+            let mut state = [Fr::from_raw([0, len as u64, 0, 0]), Fr::zero(), Fr::zero()];
+            for chunk in msg.chunks(2) {
+                for (word, elem) in state[1..].iter_mut().zip(chunk) {
+                    *word += elem;
+                }
+                poseidon2.permute(&mut state);
+            }
+            assert_eq!(t3::hash_msg(msg, None), state[0]);
+            assert_ne!(t3::hash_msg(msg, None), crate::hash_msg(msg, None));
+        }
+    }
+
+    #[test]
+    fn test_wide_external_layer() {
+        // without rounds only the initial external layer, circ(2 M4, M4) for width 8, is applied
+        let m4 = [[5u64, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+        let diag = counting::<8>();
+        let poseidon2 = Poseidon2::<8, 0, 0>::new(&[], &[], &diag);
+
+        let input = counting::<8>();
+        let mut state = input;
+        poseidon2.permute(&mut state);
+        let expected: [Fr; 8] = std::array::from_fn(|i| {
+            (0..8)
+                .map(|j| {
+                    let coeff = m4[i % 4][j % 4] * (1 + (i / 4 == j / 4) as u64);
+                    Fr::from(coeff) * input[j]
+                })
+                .sum()
+        });
+        assert_eq!(state, expected);
+    }
+}
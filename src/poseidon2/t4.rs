@@ -0,0 +1,587 @@
+use super::*;
+
+pub const T: usize = 4;
+pub const RATE: usize = 3;
+
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 56;
+
+poseidon2_instance!();
+
+/// The internal layer maps `state[i]` to `state[i] * INTERNAL_DIAG[i] + sum(state)`.
+pub(crate) static INTERNAL_DIAG: [Fr; T] = [
+    Fr::from_raw([
+        13071735289386612455,
+        937867514930142591,
+        338297992309721356,
+        1214967615784395659,
+    ]),
+    Fr::from_raw([
+        12135856085615145995,
+        11087747206803725188,
+        92802976007797685,
+        875972510381039422,
+    ]),
+    Fr::from_raw([
+        8072276821399088149,
+        12835106910674049377,
+        12882375598172350360,
+        23726925003953432,
+    ]),
+    Fr::from_raw([
+        1422103134736368267,
+        5972060781611222310,
+        3327741120806881763,
+        2462344296021899375,
+    ]),
+];
+
+pub(crate) static EXTERNAL_ROUND_CONSTANTS: [[Fr; T]; FULL_ROUNDS] = [
+    [
+        Fr::from_raw([
+            10018390284920759269,
+            196898842818127395,
+            5249540449481148995,
+            1853312570062057576,
+        ]),
+        Fr::from_raw([
+            12486221224710452438,
+            2372038863109147677,
+            8230667498854222355,
+            2764611904404804029,
+        ]),
+        Fr::from_raw([
+            4466505105966356650,
+            4686185096558265002,
+            16210260819355521378,
+            1844031548168280073,
+        ]),
+        Fr::from_raw([
+            15002325471271702008,
+            5581154705073500415,
+            1229208533183169201,
+            1549225070791782920,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            18309653156114024706,
+            798761732958817262,
+            6904962453156279281,
+            3335412762186210716,
+        ]),
+        Fr::from_raw([
+            2824096028161810206,
+            14640933461146357672,
+            957840840567621315,
+            1024001058677493842,
+        ]),
+        Fr::from_raw([
+            14339023814126516630,
+            12239068001133297662,
+            428134084092645147,
+            2673682960814460689,
+        ]),
+        Fr::from_raw([
+            6214865908119297870,
+            17923963059035301363,
+            10985380589240272449,
+            1430464474809378870,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            5109255232332580664,
+            11913027714091798733,
+            4449570166290740355,
+            864862123557185234,
+        ]),
+        Fr::from_raw([
+            2323272968957708806,
+            354488099726909104,
+            115174089281514891,
+            80808271106704719,
+        ]),
+        Fr::from_raw([
+            9646436663147525449,
+            3404572679246369876,
+            2350204275212843361,
+            1069216089054537871,
+        ]),
+        Fr::from_raw([
+            5059356740217174171,
+            4245857056683447103,
+            2426504795124362174,
+            350059533408463330,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            14876286709841668328,
+            6932857857384975351,
+            7976037835777844091,
+            738350885205242785,
+        ]),
+        Fr::from_raw([
+            16522097747524989503,
+            4157368317794149558,
+            10343110624935622906,
+            2709590753056582169,
+        ]),
+        Fr::from_raw([
+            8805379462752425633,
+            8594508728147436821,
+            15629690186821248127,
+            2936193411053712582,
+        ]),
+        Fr::from_raw([
+            17046614324338172999,
+            14086280776151114414,
+            2804088968006330580,
+            728643340397380469,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            17559938410729200952,
+            12326273425107991305,
+            8641129484519639030,
+            1699848340767391255,
+        ]),
+        Fr::from_raw([
+            3946956839294125797,
+            10123891284815211853,
+            3676846437799665248,
+            753827773683953838,
+        ]),
+        Fr::from_raw([
+            10815195850656127580,
+            17940782720817522247,
+            11666428030894512886,
+            2305765957929457259,
+        ]),
+        Fr::from_raw([
+            437280840171101279,
+            6885928680245806601,
+            6031863836827793624,
+            2698250255620259624,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            16961604592822056794,
+            12516844188945734293,
+            2404426354458718742,
+            901141949721836097,
+        ]),
+        Fr::from_raw([
+            3152898413090790038,
+            16108523113696338432,
+            11492645026300260534,
+            1417477149741880787,
+        ]),
+        Fr::from_raw([
+            10578217394647568846,
+            6637113826221079930,
+            1364449097464563400,
+            2379869735503406314,
+        ]),
+        Fr::from_raw([
+            6332539588517624153,
+            17422837239624809585,
+            12296960536238467913,
+            2434905421004621494,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            10311634121439582299,
+            2959376558854333994,
+            6697398963915560134,
+            417944321386245900,
+        ]),
+        Fr::from_raw([
+            16872849857899172004,
+            1640712307042701286,
+            16457516735210998920,
+            1084862449077757478,
+        ]),
+        Fr::from_raw([
+            10329879351081882815,
+            5178010365334480003,
+            7014208314719145622,
+            385149140585498380,
+        ]),
+        Fr::from_raw([
+            13199866221884806229,
+            10541991787372042848,
+            14909749656931548440,
+            708152185224876794,
+        ]),
+    ],
+    [
+        Fr::from_raw([
+            1717216310632203061,
+            17455832130858697862,
+            5278085098799702411,
+            227655898188482835,
+        ]),
+        Fr::from_raw([
+            17164141620747686731,
+            16689913387728553544,
+            2568326884589391367,
+            3166155980659486882,
+        ]),
+        Fr::from_raw([
+            1233442753680249567,
+            15490006495937952898,
+            7249042245074469654,
+            2138985910652398451,
+        ]),
+        Fr::from_raw([
+            4115849303762846724,
+            2230284817967990783,
+            5095423606777193313,
+            1685862792723606183,
+        ]),
+    ],
+];
+
+pub(crate) static INTERNAL_ROUND_CONSTANTS: [Fr; PARTIAL_ROUNDS] = [
+    Fr::from_raw([
+        12986735346000814543,
+        6140074342411686364,
+        6041575944194691717,
+        896092723329689904,
+    ]),
+    Fr::from_raw([
+        9573905030842087441,
+        12243211539080976096,
+        15287161151491266826,
+        1310836290481124728,
+    ]),
+    Fr::from_raw([
+        8865134002163281525,
+        6813849753829831047,
+        9066778847678578696,
+        2801725307463304665,
+    ]),
+    Fr::from_raw([
+        4931814869361681093,
+        13712769805002511750,
+        1776191062268299644,
+        2068661504023016414,
+    ]),
+    Fr::from_raw([
+        8161631444256445904,
+        3049786034047984668,
+        1021328518293651309,
+        2147500022207188878,
+    ]),
+    Fr::from_raw([
+        12766468767470212468,
+        926098071429114297,
+        17691598410912255471,
+        76565467953470566,
+    ]),
+    Fr::from_raw([
+        15547843034426617484,
+        13465733818561903358,
+        11157089789589945854,
+        3107062195097242290,
+    ]),
+    Fr::from_raw([
+        16908372174309343397,
+        17264932925429761530,
+        11508063480483774160,
+        2682419245684831641,
+    ]),
+    Fr::from_raw([
+        4870692136216401181,
+        17645600130793395310,
+        2758876031472241166,
+        874943362207641089,
+    ]),
+    Fr::from_raw([
+        4540479402638267003,
+        13477556963426049071,
+        6055112305493291757,
+        1810598527648098537,
+    ]),
+    Fr::from_raw([
+        7894770769272900997,
+        9595210915998428021,
+        7642295683223718917,
+        2210716392790471408,
+    ]),
+    Fr::from_raw([
+        10910178561156475899,
+        15811627963917441510,
+        16460518660187536520,
+        1698297851221778809,
+    ]),
+    Fr::from_raw([
+        7831732902708890908,
+        1464390598836302271,
+        8568564606321342514,
+        3007171090439369509,
+    ]),
+    Fr::from_raw([
+        12758232712903990792,
+        5937193763836963893,
+        4629415695575460109,
+        2476198378403296665,
+    ]),
+    Fr::from_raw([
+        16185652584871361881,
+        3161867062328690813,
+        8447947510117581907,
+        452436262606194895,
+    ]),
+    Fr::from_raw([
+        10531967515434376071,
+        5577695765815843856,
+        9164856352050088505,
+        1205339682110411496,
+    ]),
+    Fr::from_raw([
+        3898841196333713180,
+        14650521577519770525,
+        5736581618852866049,
+        1010789789328495026,
+    ]),
+    Fr::from_raw([
+        12103741763020280571,
+        14760208106156268938,
+        15246749619665902195,
+        1987439155030896717,
+    ]),
+    Fr::from_raw([
+        326429241861474059,
+        11335157279655967493,
+        16233357323017397007,
+        2124770605461456708,
+    ]),
+    Fr::from_raw([
+        13507610432344102875,
+        9765425316929074945,
+        10455054851855122687,
+        3371280263716451574,
+    ]),
+    Fr::from_raw([
+        9433430149246843174,
+        16916651192445074064,
+        12002862125451454299,
+        3293088726774108791,
+    ]),
+    Fr::from_raw([
+        15895963712096768440,
+        10975964170403460506,
+        7594578539046143282,
+        441635248990433378,
+    ]),
+    Fr::from_raw([
+        55564641555031451,
+        2316046008873247993,
+        6273091099984972305,
+        531938487375579818,
+    ]),
+    Fr::from_raw([
+        17845282940759944461,
+        6735239388814238924,
+        3181517889518583601,
+        2376846283559998361,
+    ]),
+    Fr::from_raw([
+        14097127963645492314,
+        1165420652731038559,
+        12527303660854712762,
+        2717289076364278965,
+    ]),
+    Fr::from_raw([
+        15600044695084040011,
+        255324662529267034,
+        11859356122961343981,
+        2571979992654075442,
+    ]),
+    Fr::from_raw([
+        1589817027469470176,
+        1086723465680833706,
+        6948011514366564799,
+        2482410610948543635,
+    ]),
+    Fr::from_raw([
+        6071201116374785253,
+        16554668458221199618,
+        16319484688832471879,
+        2792452762383364279,
+    ]),
+    Fr::from_raw([
+        13535048470209809113,
+        1831807297936988201,
+        16757520396573457190,
+        508291910620511162,
+    ]),
+    Fr::from_raw([
+        6946737468087619802,
+        14033399912488027565,
+        12701200401813783486,
+        1348363389498465135,
+    ]),
+    Fr::from_raw([
+        6788008051328210729,
+        13866524545426155292,
+        4317879914214157329,
+        2633928310905799638,
+    ]),
+    Fr::from_raw([
+        1183626302001490602,
+        10035686235057284266,
+        1656321729167440177,
+        1887128381037099784,
+    ]),
+    Fr::from_raw([
+        964566190254741199,
+        17650087760652370459,
+        14904592615785317921,
+        2929864473487096026,
+    ]),
+    Fr::from_raw([
+        13584300701347139198,
+        512534187550045064,
+        13489711551083721364,
+        41824696873363624,
+    ]),
+    Fr::from_raw([
+        17586611824788147557,
+        6430987250922925699,
+        9294838151373947091,
+        348446557360066429,
+    ]),
+    Fr::from_raw([
+        15025298913764434311,
+        14393211163878018166,
+        7154440178410267241,
+        3057088631006286899,
+    ]),
+    Fr::from_raw([
+        13451769229280519155,
+        17839347496757587523,
+        10553299811918798519,
+        2523373819901075642,
+    ]),
+    Fr::from_raw([
+        16267315463205810352,
+        13830706729545301172,
+        15413288900478726729,
+        287556136711008934,
+    ]),
+    Fr::from_raw([
+        4573780169675443044,
+        8758089751960064775,
+        2470295096511057988,
+        51551212240288730,
+    ]),
+    Fr::from_raw([
+        7093949836145798554,
+        12771428392262798771,
+        17021632567931004395,
+        1558106578814965657,
+    ]),
+    Fr::from_raw([
+        8205915653008540447,
+        10376314495036230740,
+        5774593793305666491,
+        2231830927015656581,
+    ]),
+    Fr::from_raw([
+        10783762484003267341,
+        10229708558604896492,
+        1831638669050696278,
+        2190429714552610800,
+    ]),
+    Fr::from_raw([
+        7310961803978392383,
+        12793746113455595394,
+        17036245927795997300,
+        3106081169494120044,
+    ]),
+    Fr::from_raw([
+        17421859032088162675,
+        7339791467855418851,
+        4622175020331968961,
+        590786792834928630,
+    ]),
+    Fr::from_raw([
+        14242884250645212438,
+        12806057845811725595,
+        7743423753614082490,
+        213381026777379804,
+    ]),
+    Fr::from_raw([
+        1110713325513004805,
+        8318407684973846516,
+        15952888485475298710,
+        1018983205230111328,
+    ]),
+    Fr::from_raw([
+        533883137631233338,
+        333001117808183237,
+        16968583542443855481,
+        329716098711096173,
+    ]),
+    Fr::from_raw([
+        4449676039486426793,
+        7760073051300251162,
+        5615103291054015906,
+        2516053143677338215,
+    ]),
+    Fr::from_raw([
+        16503526645482286870,
+        6358830762575712333,
+        12313512559299087688,
+        2716767262544184013,
+    ]),
+    Fr::from_raw([
+        5426798011730033104,
+        13085704829880126552,
+        6356732802364281819,
+        2175930396888807151,
+    ]),
+    Fr::from_raw([
+        8262282602783970021,
+        2576069526442506486,
+        14199683559983367515,
+        3432491072538425468,
+    ]),
+    Fr::from_raw([
+        14778817021916755205,
+        6110468871588391807,
+        2850248286812407967,
+        3411084787375678665,
+    ]),
+    Fr::from_raw([
+        4906200604739023933,
+        12096549814065429793,
+        5988343102643160344,
+        309820751832846301,
+    ]),
+    Fr::from_raw([
+        8709336210313678885,
+        10520000332606345601,
+        4756441214598660785,
+        2483744946546306397,
+    ]),
+    Fr::from_raw([
+        9617950371599090517,
+        6702332727289490762,
+        7078214601245292934,
+        215269160536524476,
+    ]),
+    Fr::from_raw([
+        14694170287735041964,
+        13462371741453101277,
+        7691247574208617782,
+        1078917709155142535,
+    ]),
+];
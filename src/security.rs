@@ -0,0 +1,459 @@
+//! Security checks for Poseidon parameter sets over [`Fr`].
+//!
+//! Round numbers are checked against the bounds of the Poseidon paper
+//! (statistical, interpolation and Gröbner basis attacks), as in the
+//! reference `calc_round_numbers.py`. MDS matrices are checked for invariant
+//! subspace trails and for infinitely long subspace trails through the
+//! partial rounds, following Grassi, Rechberger and Schofnegger, "Proving
+//! Resistance Against Infinitely Long Subspace Trails".
+
+use crate::{Field, Fr, Poseidon, PrimeField};
+
+/// Margin added on top of the minimal secure round numbers: two more full
+/// rounds and 7.5% more partial rounds.
+const FULL_ROUNDS_MARGIN: usize = 2;
+const PARTIAL_ROUNDS_MARGIN: f64 = 1.075;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundNumbers {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MdsReport {
+    /// The minimal polynomial of `M^l` is irreducible of degree `T` for every
+    /// `l` in `1..=2T`, so no power of `M` up to `2T` has an invariant
+    /// subspace and there is no invariant subspace trail of that period.
+    pub invariant_subspace_free: bool,
+    /// Largest number of consecutive partial rounds a non-zero difference
+    /// can go through without activating the S-box, `None` if unbounded.
+    pub max_inactive_rounds: Option<usize>,
+}
+
+impl MdsReport {
+    pub fn is_secure(&self) -> bool {
+        self.invariant_subspace_free && self.max_inactive_rounds.is_some()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecurityReport {
+    pub sbox_is_permutation: bool,
+    pub rounds_are_secure: bool,
+    pub min_secure_rounds: RoundNumbers,
+    pub mds: MdsReport,
+}
+
+impl SecurityReport {
+    pub fn is_secure(&self) -> bool {
+        self.sbox_is_permutation && self.rounds_are_secure && self.mds.is_secure()
+    }
+}
+
+/// Checks a whole instance with S-box `x^alpha` at `security_level` bits.
+pub fn check<const T: usize, const RF: usize, const RP: usize>(
+    poseidon: &Poseidon<'_, T, RF, RP>,
+    alpha: u64,
+    security_level: u32,
+) -> SecurityReport {
+    SecurityReport {
+        sbox_is_permutation: sbox_is_permutation(alpha),
+        rounds_are_secure: round_bounds_hold(T, alpha, security_level, RF, RP),
+        min_secure_rounds: min_secure_rounds(T, alpha, security_level),
+        mds: check_mds(poseidon.mds()),
+    }
+}
+
+/// `x^alpha` is a permutation of the field iff `gcd(alpha, p - 1) = 1`.
+pub fn sbox_is_permutation(alpha: u64) -> bool {
+    if alpha < 3 {
+        return false;
+    }
+    let p_minus_one = modulus_digits()
+        .into_iter()
+        .fold(0u128, |acc, digit| {
+            (acc * 16 + digit as u128) % alpha as u128
+        })
+        .wrapping_sub(1)
+        % alpha as u128;
+    gcd(alpha as u128, p_minus_one) == 1
+}
+
+/// Whether `full_rounds` and `partial_rounds` satisfy all attack bounds.
+pub fn round_bounds_hold(
+    width: usize,
+    alpha: u64,
+    security_level: u32,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> bool {
+    let log2_p = log2_modulus();
+    let n = log2_p.ceil();
+    let t = width as f64;
+    let m = security_level as f64;
+    let r_p = partial_rounds as f64;
+    // log_alpha(2) and log_alpha(t)
+    let log_a_2 = 2f64.ln() / (alpha as f64).ln();
+    let log_a_t = t.ln() / (alpha as f64).ln();
+
+    // statistical attacks
+    let r_f_1 = if m <= ((log2_p - (alpha as f64 - 1.0) / 2.0).floor()) * (t + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+    // interpolation attack
+    let r_f_2 = 1.0 + (log_a_2 * m.min(n)).ceil() + log_a_t.ceil() - r_p;
+    // Gröbner basis attacks
+    let r_f_3 = log_a_2 * m.min(log2_p) - r_p;
+    let r_f_4 = t - 1.0 + log_a_2 * (m / (t + 1.0)).min(log2_p / 2.0) - r_p;
+    let r_f_5 = (t - 2.0 + m / (2.0 * (alpha as f64).log2()) - r_p) / (t - 1.0);
+
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+        .into_iter()
+        .map(f64::ceil)
+        .fold(f64::MIN, f64::max);
+    full_rounds as f64 >= r_f_max
+}
+
+/// Cheapest round numbers, by S-box count, satisfying [`round_bounds_hold`].
+pub fn min_secure_rounds(width: usize, alpha: u64, security_level: u32) -> RoundNumbers {
+    cheapest_rounds(width, alpha, security_level, false)
+}
+
+/// Cheapest round numbers with the security margin of the Poseidon paper.
+///
+/// The margin is added to every secure candidate before comparing costs, as
+/// in the reference, so this is not always [`min_secure_rounds`] plus the
+/// margin. The shipped instances then round the partial rounds up to a
+/// multiple of the width, as circomlib does.
+pub fn recommended_rounds(width: usize, alpha: u64, security_level: u32) -> RoundNumbers {
+    cheapest_rounds(width, alpha, security_level, true)
+}
+
+fn cheapest_rounds(width: usize, alpha: u64, security_level: u32, margin: bool) -> RoundNumbers {
+    let mut best: Option<(usize, RoundNumbers)> = None;
+    for partial_rounds in 1..500 {
+        // the smallest full round count is the cheapest for this partial round count
+        let full_rounds = (4..100)
+            .step_by(2)
+            .find(|r_f| round_bounds_hold(width, alpha, security_level, *r_f, partial_rounds));
+        if let Some(full_rounds) = full_rounds {
+            let rounds = if margin {
+                RoundNumbers {
+                    full_rounds: full_rounds + FULL_ROUNDS_MARGIN,
+                    partial_rounds: (partial_rounds as f64 * PARTIAL_ROUNDS_MARGIN).ceil() as usize,
+                }
+            } else {
+                RoundNumbers {
+                    full_rounds,
+                    partial_rounds,
+                }
+            };
+            let cost = width * rounds.full_rounds + rounds.partial_rounds;
+            let better = match best {
+                Some((best_cost, best_rounds)) => {
+                    cost < best_cost
+                        || (cost == best_cost && rounds.full_rounds < best_rounds.full_rounds)
+                }
+                None => true,
+            };
+            if better {
+                best = Some((cost, rounds));
+            }
+        }
+    }
+    best.expect("no secure round numbers below 500 partial rounds")
+        .1
+}
+
+/// Subspace trail checks of an MDS matrix.
+pub fn check_mds<const T: usize>(mds: &[[Fr; T]; T]) -> MdsReport {
+    let mut power = *mds;
+    let mut invariant_subspace_free = true;
+    for _ in 1..=2 * T {
+        if !is_irreducible(&char_poly(&power)) {
+            invariant_subspace_free = false;
+            break;
+        }
+        power = mat_mul(&power, mds);
+    }
+
+    MdsReport {
+        invariant_subspace_free,
+        max_inactive_rounds: max_inactive_rounds(mds),
+    }
+}
+
+/// The S-box of a partial round stays inactive on the differences
+/// `d` with `(M^j d)_0 = 0` for all rounds `j` so far. That space shrinks
+/// with each independent row `e_0 M^j`; once they span everything no
+/// difference is left.
+fn max_inactive_rounds<const T: usize>(mds: &[[Fr; T]; T]) -> Option<usize> {
+    let mut rows: Vec<[Fr; T]> = Vec::with_capacity(T);
+    let mut row = [Fr::zero(); T];
+    row[0] = Fr::one();
+    loop {
+        rows.push(row);
+        match rank(&rows) {
+            // the rows stopped growing, the remaining space is never activated
+            r if r < rows.len() => return None,
+            r if r == T => return Some(T - 1),
+            _ => {}
+        }
+        // row <- row * M
+        row = std::array::from_fn(|j| (0..T).map(|k| row[k] * mds[k][j]).sum());
+    }
+}
+
+fn mat_mul<const T: usize>(a: &[[Fr; T]; T], b: &[[Fr; T]; T]) -> [[Fr; T]; T] {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..T).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn rank<const T: usize>(rows: &[[Fr; T]]) -> usize {
+    let mut rows = rows.to_vec();
+    let mut rank = 0;
+    for col in 0..T {
+        let Some(pivot) = (rank..rows.len()).find(|i| !rows[*i][col].is_zero_vartime()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inv = rows[rank][col].invert().unwrap();
+        let pivot_row = rows[rank].map(|v| v * inv);
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != rank {
+                let factor = row[col];
+                for (v, p) in row.iter_mut().zip(pivot_row.iter()) {
+                    *v -= factor * p;
+                }
+            }
+        }
+        rows[rank] = pivot_row;
+        rank += 1;
+    }
+    rank
+}
+
+/// Characteristic polynomial by Faddeev-LeVerrier, coefficients from the
+/// constant term up, monic.
+fn char_poly<const T: usize>(m: &[[Fr; T]; T]) -> Vec<Fr> {
+    let mut coeffs = vec![Fr::zero(); T + 1];
+    coeffs[T] = Fr::one();
+    let mut aux = [[Fr::zero(); T]; T];
+    for k in 1..=T {
+        // aux <- M * aux + c_{T-k+1} I
+        aux = mat_mul(m, &aux);
+        for i in 0..T {
+            aux[i][i] += coeffs[T - k + 1];
+        }
+        let trace: Fr = (0..T)
+            .map(|i| (0..T).map(|j| m[i][j] * aux[j][i]).sum::<Fr>())
+            .sum();
+        coeffs[T - k] = -trace * Fr::from(k as u64).invert().unwrap();
+    }
+    coeffs
+}
+
+/// Rabin's test: a monic `f` of degree `n` is irreducible iff
+/// `x^(p^n) = x mod f` and `gcd(x^(p^(n/q)) - x, f) = 1` for all primes `q | n`.
+fn is_irreducible(f: &[Fr]) -> bool {
+    let n = f.len() - 1;
+    let p = modulus_bits();
+    let x = vec![Fr::zero(), Fr::one()];
+
+    // frobenius[k] = x^(p^k) mod f
+    let mut frobenius = vec![poly_rem(&x, f)];
+    for k in 1..=n {
+        let prev = &frobenius[k - 1];
+        frobenius.push(poly_pow_mod(prev, &p, f));
+    }
+
+    let minus_x = |g: &[Fr]| {
+        let mut g = g.to_vec();
+        g.resize(g.len().max(2), Fr::zero());
+        g[1] -= Fr::one();
+        g
+    };
+    if !trim(minus_x(&frobenius[n])).is_empty() {
+        return false;
+    }
+    prime_factors(n)
+        .into_iter()
+        .all(|q| poly_gcd(minus_x(&frobenius[n / q]), f.to_vec()).len() == 1)
+}
+
+fn trim(mut a: Vec<Fr>) -> Vec<Fr> {
+    while a.last().is_some_and(|c| c.is_zero_vartime()) {
+        a.pop();
+    }
+    a
+}
+
+fn poly_rem(a: &[Fr], f: &[Fr]) -> Vec<Fr> {
+    let f = trim(f.to_vec());
+    let d = f.len() - 1;
+    let lead_inv = f[d].invert().unwrap();
+    let mut a = trim(a.to_vec());
+    while a.len() > d {
+        let coeff = a[a.len() - 1] * lead_inv;
+        let shift = a.len() - 1 - d;
+        for (i, c) in f.iter().enumerate() {
+            a[shift + i] -= coeff * c;
+        }
+        a = trim(a);
+    }
+    a
+}
+
+fn poly_mul_mod(a: &[Fr], b: &[Fr], f: &[Fr]) -> Vec<Fr> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut r = vec![Fr::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            r[i + j] += x * y;
+        }
+    }
+    poly_rem(&r, f)
+}
+
+fn poly_pow_mod(base: &[Fr], exp_bits: &[bool], f: &[Fr]) -> Vec<Fr> {
+    let mut acc = vec![Fr::one()];
+    for bit in exp_bits {
+        acc = poly_mul_mod(&acc, &acc, f);
+        if *bit {
+            acc = poly_mul_mod(&acc, base, f);
+        }
+    }
+    acc
+}
+
+fn poly_gcd(a: Vec<Fr>, b: Vec<Fr>) -> Vec<Fr> {
+    let (mut a, mut b) = (trim(a), trim(b));
+    while !b.is_empty() {
+        let r = poly_rem(&a, &b);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = vec![];
+    let mut d = 2;
+    while d * d <= n {
+        if n / d * d == n {
+            factors.push(d);
+            while n / d * d == n {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn modulus_digits() -> Vec<u32> {
+    Fr::MODULUS
+        .trim_start_matches("0x")
+        .chars()
+        .map(|c| c.to_digit(16).expect("modulus is hex"))
+        .collect()
+}
+
+/// Big-endian bits of the modulus, without leading zeros.
+fn modulus_bits() -> Vec<bool> {
+    let bits: Vec<bool> = modulus_digits()
+        .into_iter()
+        .flat_map(|nibble| (0..4).rev().map(move |i| (nibble >> i) & 1 == 1))
+        .collect();
+    let first = bits.iter().position(|bit| *bit).unwrap();
+    bits[first..].to_vec()
+}
+
+fn log2_modulus() -> f64 {
+    let bits = modulus_bits();
+    let top = bits[..64]
+        .iter()
+        .fold(0u64, |acc, bit| (acc << 1) | *bit as u64);
+    (top as f64).log2() + (bits.len() - 64) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{widths, FULL_ROUNDS, PARTIAL_ROUNDS, POSEIDON};
+
+    // recommended rounds with the partial rounds rounded up to a multiple of `width`
+    fn shipped_rounds(width: usize) -> (usize, usize) {
+        let rounds = recommended_rounds(width, 5, 128);
+        (
+            rounds.full_rounds,
+            rounds.partial_rounds.div_ceil(width) * width,
+        )
+    }
+
+    #[test]
+    fn test_shipped_instance_is_secure() {
+        let report = check(&POSEIDON, 5, 128);
+        assert!(report.is_secure(), "{report:?}");
+        assert_eq!(
+            recommended_rounds(3, 5, 128),
+            RoundNumbers {
+                full_rounds: FULL_ROUNDS,
+                partial_rounds: 56,
+            }
+        );
+        assert_eq!(shipped_rounds(3), (FULL_ROUNDS, PARTIAL_ROUNDS));
+        assert_eq!(report.mds.max_inactive_rounds, Some(2));
+    }
+
+    #[test]
+    fn test_widths_rounds() {
+        macro_rules! check_rounds {
+            ($($t:ident),*) => {$(
+                assert_eq!(
+                    shipped_rounds(widths::$t::T),
+                    (widths::$t::FULL_ROUNDS, widths::$t::PARTIAL_ROUNDS)
+                );
+                assert!(round_bounds_hold(
+                    widths::$t::T,
+                    5,
+                    128,
+                    widths::$t::FULL_ROUNDS,
+                    widths::$t::PARTIAL_ROUNDS,
+                ));
+            )*};
+        }
+        check_rounds!(t2, t3, t4, t5, t6, t7, t8, t9, t10, t11, t12, t13);
+    }
+
+    #[test]
+    fn test_insecure_parameters() {
+        assert!(sbox_is_permutation(5));
+        assert!(!sbox_is_permutation(3));
+        assert!(!round_bounds_hold(3, 5, 128, 8, 20));
+        assert!(!round_bounds_hold(3, 5, 128, 4, 57));
+
+        // the identity keeps every subspace invariant and never activates the S-box
+        let one = Fr::one();
+        let zero = Fr::zero();
+        let identity = [[one, zero, zero], [zero, one, zero], [zero, zero, one]];
+        let report = check_mds(&identity);
+        assert!(!report.invariant_subspace_free);
+        assert_eq!(report.max_inactive_rounds, None);
+    }
+}
@@ -0,0 +1,398 @@
+//! Sponge API for field elements, following the SAFE construction.
+//!
+//! The sequence of absorb/squeeze calls is declared up front as an
+//! [`IoPattern`]. The pattern and a domain separator are hashed into a tag
+//! that initialises the capacity element (`state[0]`), so transcripts with
+//! different shapes or domains never share a sponge state.
+
+use crate::{imp, Fr, MsgHasher, State, RATE};
+use std::fmt;
+use std::ops::AddAssign;
+
+const ABSORB_FLAG: u32 = 1 << 31;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeOp {
+    Absorb(u32),
+    Squeeze(u32),
+}
+
+impl SpongeOp {
+    fn len(&self) -> u32 {
+        match self {
+            SpongeOp::Absorb(n) | SpongeOp::Squeeze(n) => *n,
+        }
+    }
+
+    fn with_len(&self, len: u32) -> Self {
+        match self {
+            SpongeOp::Absorb(_) => SpongeOp::Absorb(len),
+            SpongeOp::Squeeze(_) => SpongeOp::Squeeze(len),
+        }
+    }
+
+    fn same_kind(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (SpongeOp::Absorb(_), SpongeOp::Absorb(_))
+                | (SpongeOp::Squeeze(_), SpongeOp::Squeeze(_))
+        )
+    }
+
+    /// SAFE encoding: the MSB marks an absorb, the remaining 31 bits are the length.
+    fn encode(&self) -> u32 {
+        match self {
+            SpongeOp::Absorb(n) => ABSORB_FLAG | n,
+            SpongeOp::Squeeze(n) => *n,
+        }
+    }
+}
+
+/// Aggregated sequence of sponge operations.
+///
+/// Consecutive operations of the same kind are merged and empty operations
+/// are dropped, so `[Absorb(1), Absorb(2)]` and `[Absorb(3)]` are the same
+/// pattern.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IoPattern(Vec<SpongeOp>);
+
+impl IoPattern {
+    /// # Panics
+    ///
+    /// If an aggregated operation is `2^31` or longer, see [`Self::try_new`].
+    pub fn new(ops: impl IntoIterator<Item = SpongeOp>) -> Self {
+        Self::try_new(ops).expect("sponge op length overflows 31 bits")
+    }
+
+    /// Fails with [`SpongeError::LengthOverflow`] if an aggregated operation
+    /// does not fit the 31 bits of its encoding.
+    pub fn try_new(ops: impl IntoIterator<Item = SpongeOp>) -> Result<Self, SpongeError> {
+        let mut aggregated: Vec<SpongeOp> = Vec::new();
+        for op in ops {
+            if op.len() == 0 {
+                continue;
+            }
+            match aggregated.last_mut() {
+                Some(last) if last.same_kind(&op) => {
+                    let len = last.len() as usize + op.len() as usize;
+                    *last = last.with_len(op_len(len)?);
+                }
+                _ => {
+                    op_len(op.len() as usize)?;
+                    aggregated.push(op);
+                }
+            }
+        }
+        Ok(Self(aggregated))
+    }
+
+    pub fn ops(&self) -> &[SpongeOp] {
+        &self.0
+    }
+
+    /// Tag of the pattern under `domain`: `hash_msg([w_0, .., w_k, domain], None)`
+    /// where `w_i` are the encoded operations.
+    pub fn tag(&self, domain: Fr) -> Fr {
+        let mut hasher = MsgHasher::with_msg_len(self.0.len() + 1);
+        for op in self.0.iter() {
+            hasher.update_one(Fr::from(op.encode() as u64));
+        }
+        hasher.update_one(domain);
+        hasher.finalize()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeError {
+    /// The call does not match the next operation of the pattern.
+    PatternMismatch {
+        expected: Option<SpongeOp>,
+        got: SpongeOp,
+    },
+    /// `finish` was called before the whole pattern was used.
+    Unfinished { remaining: usize },
+    /// The length does not fit the 31 bits of an encoded operation.
+    LengthOverflow { len: usize },
+}
+
+impl fmt::Display for SpongeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpongeError::PatternMismatch {
+                expected: Some(expected),
+                got,
+            } => write!(f, "io pattern mismatch: expected {expected:?}, got {got:?}"),
+            SpongeError::PatternMismatch {
+                expected: None,
+                got,
+            } => write!(f, "io pattern mismatch: pattern exhausted, got {got:?}"),
+            SpongeError::Unfinished { remaining } => {
+                write!(f, "io pattern unfinished: {remaining} operations left")
+            }
+            SpongeError::LengthOverflow { len } => {
+                write!(f, "sponge op length {len} overflows 31 bits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpongeError {}
+
+/// SAFE sponge over the crate's `T = 3` permutation, with `state[0]` as
+/// capacity and `state[1..]` as rate.
+#[derive(Clone, Debug)]
+pub struct Sponge {
+    state: State,
+    pattern: IoPattern,
+    // index of the current op and how much of it has been used
+    op_index: usize,
+    op_used: u32,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+}
+
+impl Sponge {
+    pub fn new(pattern: IoPattern, domain: Fr) -> Self {
+        let tag = pattern.tag(domain);
+        Self {
+            state: [tag, Fr::zero(), Fr::zero()],
+            pattern,
+            op_index: 0,
+            op_used: 0,
+            absorb_pos: 0,
+            // the first squeeze permutes, also without a prior absorb
+            squeeze_pos: RATE,
+        }
+    }
+
+    pub fn absorb(&mut self, input: &[Fr]) -> Result<(), SpongeError> {
+        self.consume(SpongeOp::Absorb(op_len(input.len())?))?;
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        for elem in input {
+            if self.absorb_pos == RATE {
+                imp::permute(&mut self.state);
+                self.absorb_pos = 0;
+            }
+            self.state[1 + self.absorb_pos].add_assign(elem);
+            self.absorb_pos += 1;
+        }
+        // force a permutation before the next squeeze
+        self.squeeze_pos = RATE;
+        Ok(())
+    }
+
+    pub fn squeeze(&mut self, len: usize) -> Result<Vec<Fr>, SpongeError> {
+        self.consume(SpongeOp::Squeeze(op_len(len)?))?;
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut output = Vec::with_capacity(len);
+        for _ in 0..len {
+            if self.squeeze_pos == RATE {
+                imp::permute(&mut self.state);
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            output.push(self.state[1 + self.squeeze_pos]);
+            self.squeeze_pos += 1;
+        }
+        Ok(output)
+    }
+
+    /// Checks that the whole pattern has been used.
+    pub fn finish(self) -> Result<(), SpongeError> {
+        match self.pattern.0.len() - self.op_index {
+            0 => Ok(()),
+            remaining => Err(SpongeError::Unfinished { remaining }),
+        }
+    }
+
+    fn consume(&mut self, call: SpongeOp) -> Result<(), SpongeError> {
+        if call.len() == 0 {
+            return Ok(());
+        }
+        let expected = self.pattern.0.get(self.op_index).copied();
+        match expected {
+            Some(op) if op.same_kind(&call) && call.len() <= op.len() - self.op_used => {
+                self.op_used += call.len();
+                if self.op_used == op.len() {
+                    self.op_index += 1;
+                    self.op_used = 0;
+                }
+                Ok(())
+            }
+            _ => Err(SpongeError::PatternMismatch {
+                expected: expected.map(|op| op.with_len(op.len() - self.op_used)),
+                got: call,
+            }),
+        }
+    }
+}
+
+fn op_len(len: usize) -> Result<u32, SpongeError> {
+    match u32::try_from(len) {
+        Ok(len) if len < ABSORB_FLAG => Ok(len),
+        _ => Err(SpongeError::LengthOverflow { len }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use SpongeOp::*;
+
+    fn elems(range: std::ops::Range<u64>) -> Vec<Fr> {
+        range.map(Fr::from).collect()
+    }
+
+    #[test]
+    fn test_io_pattern() {
+        let pattern = IoPattern::new([Absorb(1), Absorb(2), Squeeze(0), Squeeze(1), Absorb(0)]);
+        assert_eq!(pattern.ops(), &[Absorb(3), Squeeze(1)]);
+
+        let domain = Fr::from(7u64);
+        assert_eq!(
+            pattern.tag(domain),
+            IoPattern::new([Absorb(3), Squeeze(1)]).tag(domain)
+        );
+        assert_ne!(pattern.tag(domain), pattern.tag(Fr::from(8u64)));
+        assert_ne!(
+            pattern.tag(domain),
+            IoPattern::new([Absorb(3), Squeeze(2)]).tag(domain)
+        );
+        let half = 1 << 30;
+        assert_eq!(
+            IoPattern::try_new([Absorb(half), Squeeze(1), Squeeze(half), Absorb(half - 1)]),
+            Ok(IoPattern::new([
+                Absorb(half),
+                Squeeze(half + 1),
+                Absorb(half - 1)
+            ]))
+        );
+        assert_eq!(
+            IoPattern::try_new([Squeeze(half), Squeeze(half)]),
+            Err(SpongeError::LengthOverflow { len: 1 << 31 })
+        );
+        assert_eq!(
+            IoPattern::try_new([Absorb(u32::MAX)]),
+            Err(SpongeError::LengthOverflow {
+                len: u32::MAX as usize
+            })
+        );
+    }
+
+    #[test]
+    fn test_sponge() {
+        let pattern = IoPattern::new([Absorb(5), Squeeze(3), Absorb(1), Squeeze(2)]);
+        let domain = Fr::from(1u64);
+
+        let mut sponge = Sponge::new(pattern.clone(), domain);
+        sponge.absorb(&elems(0..5)).unwrap();
+        let out1 = sponge.squeeze(3).unwrap();
+        sponge.absorb(&elems(5..6)).unwrap();
+        let out2 = sponge.squeeze(2).unwrap();
+        sponge.finish().unwrap();
+
+        // calls may split an operation of the pattern
+        let mut sponge = Sponge::new(pattern.clone(), domain);
+        sponge.absorb(&elems(0..2)).unwrap();
+        sponge.absorb(&elems(2..5)).unwrap();
+        let mut split = sponge.squeeze(1).unwrap();
+        split.extend(sponge.squeeze(2).unwrap());
+        assert_eq!(split, out1);
+        sponge.absorb(&elems(5..6)).unwrap();
+        assert_eq!(sponge.squeeze(2).unwrap(), out2);
+        sponge.finish().unwrap();
+
+        // empty calls are not part of the pattern and do not touch the state
+        let mut sponge = Sponge::new(pattern.clone(), domain);
+        sponge.absorb(&elems(0..5)).unwrap();
+        let mut split = sponge.squeeze(1).unwrap();
+        sponge.absorb(&[]).unwrap();
+        split.extend(sponge.squeeze(2).unwrap());
+        assert_eq!(split, out1);
+        sponge.absorb(&elems(5..6)).unwrap();
+        assert_eq!(sponge.squeeze(0).unwrap(), vec![]);
+        assert_eq!(sponge.squeeze(2).unwrap(), out2);
+        sponge.finish().unwrap();
+
+        // every full or partial rate block is followed by a permutation, then the rate is read
+        let mut state = [pattern.tag(domain), Fr::zero(), Fr::zero()];
+        let msg = elems(0..5);
+        for chunk in msg.chunks(RATE) {
+            for (i, elem) in chunk.iter().enumerate() {
+                state[1 + i] += elem;
+            }
+            imp::permute(&mut state);
+        }
+        assert_eq!(out1[..2], state[1..]);
+        imp::permute(&mut state);
+        assert_eq!(out1[2], state[1]);
+    }
+
+    #[test]
+    fn test_sponge_errors() {
+        let pattern = IoPattern::new([Absorb(2), Squeeze(1)]);
+        let mut sponge = Sponge::new(pattern.clone(), Fr::zero());
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SpongeError::PatternMismatch {
+                expected: Some(Absorb(2)),
+                got: Squeeze(1),
+            })
+        );
+        sponge.absorb(&elems(0..1)).unwrap();
+        assert_eq!(
+            sponge.absorb(&elems(0..2)),
+            Err(SpongeError::PatternMismatch {
+                expected: Some(Absorb(1)),
+                got: Absorb(2),
+            })
+        );
+        assert_eq!(
+            sponge.clone().finish(),
+            Err(SpongeError::Unfinished { remaining: 2 })
+        );
+        sponge.absorb(&elems(1..2)).unwrap();
+        sponge.squeeze(1).unwrap();
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SpongeError::PatternMismatch {
+                expected: None,
+                got: Squeeze(1),
+            })
+        );
+        sponge.finish().unwrap();
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_length_overflow() {
+        // truncated to 32 bits this call would match Squeeze(1)
+        let len = (1usize << 32) + 1;
+        let mut sponge = Sponge::new(IoPattern::new([Squeeze(1)]), Fr::zero());
+        assert_eq!(
+            sponge.squeeze(len),
+            Err(SpongeError::LengthOverflow { len })
+        );
+        assert_eq!(sponge.squeeze(1).map(|out| out.len()), Ok(1));
+    }
+
+    #[test]
+    fn test_squeeze_first() {
+        let pattern = IoPattern::new([Squeeze(3)]);
+        let domain = Fr::from(5u64);
+        let mut sponge = Sponge::new(pattern.clone(), domain);
+        let out = sponge.squeeze(3).unwrap();
+
+        let mut state = [pattern.tag(domain), Fr::zero(), Fr::zero()];
+        imp::permute(&mut state);
+        assert_eq!(out[..2], state[1..]);
+        imp::permute(&mut state);
+        assert_eq!(out[2], state[1]);
+    }
+}
@@ -0,0 +1,96 @@
+//! Poseidon instances over BN254 for other widths.
+//!
+//! Every `tN` module holds the parameters for width `T = N` (rate `N - 1`)
+//! with the standard 8 full rounds and the partial round count from the
+//! Poseidon paper, generated by the reference Grain LFSR. They are the same
+//! parameter sets as circomlib's `poseidon`, with `tN::hash_with_domain(inp, 0)`
+//! equal to circomlib `poseidon(inp)`. The default instance is [`t3`].
+
+use crate::{Fr, Poseidon};
+
+macro_rules! width_instance {
+    () => {
+        width_instance!(Poseidon::new(&ROUND_CONSTANTS, &MDS));
+    };
+    ($instance:expr) => {
+        pub static POSEIDON: Poseidon<'static, T, FULL_ROUNDS, PARTIAL_ROUNDS> = $instance;
+
+        /// Like [`crate::hash_with_domain`], for this width.
+        pub fn hash_with_domain(inp: &[Fr; RATE], domain: Fr) -> Fr {
+            POSEIDON.hash_with_domain(inp, domain)
+        }
+
+        /// Like [`crate::hash_msg`], absorbing `RATE` elements per permutation.
+        pub fn hash_msg(msg: &[Fr], cap: Option<u128>) -> Fr {
+            POSEIDON.hash_msg(msg, cap)
+        }
+    };
+}
+
+pub mod t10;
+pub mod t11;
+pub mod t12;
+pub mod t13;
+pub mod t2;
+pub mod t3;
+pub mod t4;
+pub mod t5;
+pub mod t6;
+pub mod t7;
+pub mod t8;
+pub mod t9;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimeField;
+
+    fn fr(hex: &str) -> Fr {
+        let mut repr = [0u8; 32];
+        for (i, byte) in repr.iter_mut().enumerate() {
+            let pos = hex.len() - 2 * (i + 1);
+            *byte = u8::from_str_radix(&hex[pos..pos + 2], 16).unwrap();
+        }
+        Fr::from_repr_vartime(repr).unwrap()
+    }
+
+    #[test]
+    fn test_circomlib_vectors() {
+        let inp = |n: u64| -> Vec<Fr> { (1..=n).map(Fr::from).collect() };
+
+        assert_eq!(
+            t2::hash_with_domain(inp(1)[..].try_into().unwrap(), Fr::zero()),
+            fr("29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133")
+        );
+        assert_eq!(
+            t3::hash_with_domain(inp(2)[..].try_into().unwrap(), Fr::zero()),
+            fr("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+        );
+        assert_eq!(
+            t4::hash_with_domain(inp(3)[..].try_into().unwrap(), Fr::zero()),
+            fr("0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732")
+        );
+        assert_eq!(
+            t5::hash_with_domain(inp(4)[..].try_into().unwrap(), Fr::zero()),
+            fr("299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465")
+        );
+    }
+
+    #[test]
+    fn test_t3_matches_default() {
+        let msg: Vec<_> = (0..9u64).map(Fr::from).collect();
+        for len in 0..msg.len() {
+            for cap in [None, Some(0u128), Some(3)] {
+                assert_eq!(
+                    t3::hash_msg(&msg[..len], cap),
+                    crate::hash_msg(&msg[..len], cap)
+                );
+            }
+        }
+        let inp = [Fr::from(1u64), Fr::from(2u64)];
+        assert_eq!(
+            t3::hash_with_domain(&inp, Fr::from(3u64)),
+            crate::hash_with_domain(&inp, Fr::from(3u64))
+        );
+    }
+}
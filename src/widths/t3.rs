@@ -0,0 +1,18 @@
+use super::*;
+use crate::constants::{
+    SPARSE_FIRST_COLUMNS, SPARSE_FIRST_ROWS, SPARSE_PARTIAL_CONSTANTS, SPARSE_PRE_CONSTANTS,
+    SPARSE_PRE_MDS,
+};
+use crate::imp::Sparse;
+
+pub use crate::constants::{FULL_ROUNDS, PARTIAL_ROUNDS, RATE, T};
+
+width_instance!(Poseidon::new(&ROUND_CONSTANTS, &MDS).with_sparse(Sparse {
+    pre_mds: &SPARSE_PRE_MDS,
+    pre_constants: &SPARSE_PRE_CONSTANTS,
+    partial_constants: &SPARSE_PARTIAL_CONSTANTS,
+    first_rows: &SPARSE_FIRST_ROWS,
+    first_columns: &SPARSE_FIRST_COLUMNS,
+}));
+
+pub(crate) use crate::constants::{MDS, ROUND_CONSTANTS};
@@ -0,0 +1,103 @@
+use crate::{imp, Fr, MsgHasher, State, RATE};
+
+/// Extendable output of a sponge, created by [`hash_msg_xof`] or
+/// [`hash_with_domain_xof`].
+///
+/// Starting from the state right after absorption, the iterator yields the
+/// rate elements `state[1], state[2]`, then permutes and yields `state[1],
+/// state[2]` again, and so on. The capacity element `state[0]`, which is the
+/// output of [`hash_msg`](crate::hash_msg) / [`hash_with_domain`](crate::hash_with_domain),
+/// is never yielded. The iterator is infinite.
+#[derive(Clone, Debug)]
+pub struct Xof {
+    state: State,
+    pos: usize,
+}
+
+impl Xof {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state, pos: 0 }
+    }
+}
+
+impl Iterator for Xof {
+    type Item = Fr;
+
+    fn next(&mut self) -> Option<Fr> {
+        if self.pos == RATE {
+            imp::permute(&mut self.state);
+            self.pos = 0;
+        }
+        let output = self.state[1 + self.pos];
+        self.pos += 1;
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// XOF variant of [`hash_msg`](crate::hash_msg), absorbing the same way.
+pub fn hash_msg_xof(msg: &[Fr], cap: Option<u128>) -> Xof {
+    let mut hasher = match cap {
+        Some(cap) => MsgHasher::new(cap),
+        None => MsgHasher::with_msg_len(msg.len()),
+    };
+    hasher.update(msg);
+    hasher.finalize_xof()
+}
+
+/// XOF variant of [`hash_with_domain`](crate::hash_with_domain).
+pub fn hash_with_domain_xof(inp: &[Fr; 2], domain: Fr) -> Xof {
+    let mut state = [domain, inp[0], inp[1]];
+    imp::permute(&mut state);
+    Xof::new(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_msg, hash_with_domain};
+
+    #[test]
+    fn test_xof_order() {
+        let inp = [Fr::from(1u64), Fr::from(2u64)];
+        let domain = Fr::from(3u64);
+
+        let mut state = [domain, inp[0], inp[1]];
+        imp::permute(&mut state);
+        assert_eq!(state[0], hash_with_domain(&inp, domain));
+
+        let mut expected = vec![];
+        for _ in 0..3 {
+            expected.extend_from_slice(&state[1..]);
+            imp::permute(&mut state);
+        }
+        let output: Vec<_> = hash_with_domain_xof(&inp, domain).take(6).collect();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_hash_msg_xof() {
+        let msg: Vec<_> = (0..7u64).map(Fr::from).collect();
+
+        for len in 0..msg.len() {
+            for cap in [None, Some(0u128), Some(5)] {
+                let msg = &msg[..len];
+                let mut hasher = match cap {
+                    Some(cap) => MsgHasher::new(cap),
+                    None => MsgHasher::with_msg_len(len),
+                };
+                hasher.update(msg);
+                let mut state = hasher.finalize_state();
+                assert_eq!(state[0], hash_msg(msg, cap));
+
+                let output: Vec<_> = hash_msg_xof(msg, cap).take(3).collect();
+                assert_eq!(output[..2], state[1..]);
+                imp::permute(&mut state);
+                assert_eq!(output[2], state[1]);
+            }
+        }
+    }
+}
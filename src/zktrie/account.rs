@@ -0,0 +1,98 @@
+use super::{fr_to_be_bytes, hash_value, ZkTrieError};
+use crate::Fr;
+
+/// Compressed flags of an account value, only the keccak code hash is
+/// hashed as a 32-byte word.
+pub const ACCOUNT_COMPRESSED_FLAGS: u32 = 1 << 3;
+
+/// State account as stored in the zkTrie.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Account {
+    pub nonce: u64,
+    pub code_size: u64,
+    /// Big-endian.
+    pub balance: [u8; 32],
+    pub storage_root: Fr,
+    pub keccak_code_hash: [u8; 32],
+    /// [`hash_code`](crate::hash_code) of the code.
+    pub poseidon_code_hash: [u8; 32],
+}
+
+impl Account {
+    /// The leaf value: `code_size << 64 | nonce`, balance, storage root,
+    /// keccak code hash and poseidon code hash as big-endian words.
+    pub fn fields(&self) -> [[u8; 32]; 5] {
+        let mut packed = [0u8; 32];
+        packed[16..24].copy_from_slice(&self.code_size.to_be_bytes());
+        packed[24..].copy_from_slice(&self.nonce.to_be_bytes());
+        [
+            packed,
+            self.balance,
+            fr_to_be_bytes(self.storage_root),
+            self.keccak_code_hash,
+            self.poseidon_code_hash,
+        ]
+    }
+}
+
+/// Value hash of the account leaf, with [`ACCOUNT_COMPRESSED_FLAGS`].
+///
+/// Fails if the balance or the poseidon code hash is not a canonical field
+/// element.
+pub fn hash_account(account: &Account) -> Result<Fr, ZkTrieError> {
+    hash_value(ACCOUNT_COMPRESSED_FLAGS, &account.fields())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zktrie::{fr_from_be_bytes, hash_byte32, hash_elems};
+    use crate::{hash_code, hash_with_domain};
+
+    #[test]
+    fn test_hash_account() {
+        // TODO: add a Morph mainnet account with its expected leaf value
+        // hash; this only checks the field layout and flags of the zktrie
+        // account encoding
+        let mut balance = [0u8; 32];
+        balance[30..].copy_from_slice(&1000u16.to_be_bytes());
+        let account = Account {
+            nonce: 5,
+            code_size: 3,
+            balance,
+            storage_root: Fr::from(77u64),
+            keccak_code_hash: [0xc5; 32],
+            poseidon_code_hash: hash_code(&[1, 2, 3]),
+        };
+
+        let fields = account.fields();
+        assert_eq!(
+            fr_from_be_bytes(&fields[0]),
+            Some(Fr::from_raw([5, 3, 0, 0]))
+        );
+        assert_eq!(fr_from_be_bytes(&fields[2]), Some(Fr::from(77u64)));
+
+        let keccak = hash_byte32(&[0xc5; 32]);
+        let poseidon = fr_from_be_bytes(&account.poseidon_code_hash).unwrap();
+        let expected = hash_elems(
+            Fr::from_raw([5, 3, 0, 0]),
+            Fr::from(1000u64),
+            &[Fr::from(77u64), keccak, poseidon],
+        );
+        assert_eq!(hash_account(&account), Ok(expected));
+
+        let domain = Fr::from(1280u64);
+        let h = |a, b| hash_with_domain(&[a, b], domain);
+        let base = h(Fr::from_raw([5, 3, 0, 0]), Fr::from(1000u64));
+        assert_eq!(expected, h(h(base, h(Fr::from(77u64), keccak)), poseidon));
+
+        let overflow = Account {
+            balance: [0xff; 32],
+            ..account
+        };
+        assert_eq!(
+            hash_account(&overflow),
+            Err(ZkTrieError::NonCanonicalValue { index: 1 })
+        );
+    }
+}
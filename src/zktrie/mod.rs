@@ -0,0 +1,353 @@
+//! Node hashing of the zkTrie used for the Scroll and Morph state.
+//!
+//! Every hash is [`hash_with_domain`] of two field elements, the domain
+//! telling apart the node types and the number of hashed elements:
+//!
+//! - a branch node hashes its children with its [`BranchType`], 6 to 9;
+//! - a leaf node hashes its node key and value hash with domain 4;
+//! - the empty node hash is zero;
+//! - a value of `n >= 2` elements is folded by [`hash_elems`] with domain
+//!   `n * 256`, and a 32-byte word that may not fit the field is split into
+//!   two 16-byte big-endian halves hashed with domain 512.
+//!
+//! [`ZkTrie`] is the sparse binary trie over these nodes. A 32-byte key is
+//! placed under the node key [`hash_byte32`]`(key)`, whose little-endian bits
+//! are the path from the root. A subtree holding a single leaf is that leaf,
+//! so the root only depends on the leaves.
+
+mod account;
+mod node;
+mod trie;
+
+pub use account::{hash_account, Account, ACCOUNT_COMPRESSED_FLAGS};
+pub use node::{BranchNode, BranchType, EmptyNode, LeafNode, Node};
+pub use trie::{verify_proof, MemoryNodeStore, NodeStore, Proof, ZkTrie, MAX_LEVELS};
+
+use crate::{hash_with_domain, Fr, PrimeField};
+use std::convert::Infallible;
+use std::fmt;
+
+/// Domain step per hashed element in [`hash_elems`].
+pub const HASH_DOMAIN_ELEMS_BASE: u64 = 256;
+/// Domain of [`hash_byte32`], two elements.
+pub const HASH_DOMAIN_BYTE32: u64 = 2 * HASH_DOMAIN_ELEMS_BASE;
+
+/// Error of the zkTrie, `E` is the error of the [`NodeStore`] when there is
+/// one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZkTrieError<E = Infallible> {
+    /// A leaf value needs at least one element.
+    EmptyValue,
+    /// Element `index` of a leaf value is not flagged as compressed and is
+    /// not a canonical big-endian field element.
+    NonCanonicalValue { index: usize },
+    /// The store has no node with this hash.
+    MissingNode { hash: Fr },
+    /// Two node keys share the first [`MAX_LEVELS`] path bits.
+    ReachedMaxLevel,
+    /// The proof does not lead from the root to the key.
+    InvalidProof,
+    /// The [`NodeStore`] failed.
+    Store(E),
+}
+
+impl ZkTrieError {
+    // errors raised without a store, as the error of any store
+    pub(crate) fn lift<E>(self) -> ZkTrieError<E> {
+        match self {
+            ZkTrieError::EmptyValue => ZkTrieError::EmptyValue,
+            ZkTrieError::NonCanonicalValue { index } => ZkTrieError::NonCanonicalValue { index },
+            ZkTrieError::MissingNode { hash } => ZkTrieError::MissingNode { hash },
+            ZkTrieError::ReachedMaxLevel => ZkTrieError::ReachedMaxLevel,
+            ZkTrieError::InvalidProof => ZkTrieError::InvalidProof,
+            ZkTrieError::Store(never) => match never {},
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ZkTrieError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZkTrieError::EmptyValue => write!(f, "empty leaf value"),
+            ZkTrieError::NonCanonicalValue { index } => {
+                write!(
+                    f,
+                    "leaf value element {index} is not a canonical field element"
+                )
+            }
+            ZkTrieError::MissingNode { hash } => write!(f, "missing trie node {hash:?}"),
+            ZkTrieError::ReachedMaxLevel => write!(f, "reached the maximum trie depth"),
+            ZkTrieError::InvalidProof => write!(f, "invalid trie proof"),
+            ZkTrieError::Store(err) => write!(f, "trie node store: {err}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ZkTrieError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ZkTrieError::Store(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Hashes `[fst, snd, elems..]` under `domain`: the elements after the
+/// first two are hashed in pairs, an odd one is carried over, and the
+/// result is folded into the hash of the first two, recursively.
+pub fn hash_elems_with_domain(domain: u64, fst: Fr, snd: Fr, elems: &[Fr]) -> Fr {
+    let domain_fr = Fr::from(domain);
+    let base = hash_with_domain(&[fst, snd], domain_fr);
+    match elems {
+        [] => base,
+        [elem] => hash_with_domain(&[base, *elem], domain_fr),
+        _ => {
+            let paired: Vec<Fr> = elems
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_with_domain(&[*a, *b], domain_fr),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            hash_elems_with_domain(domain, base, paired[0], &paired[1..])
+        }
+    }
+}
+
+/// [`hash_elems_with_domain`] with the domain of `2 + elems.len()` elements.
+pub fn hash_elems(fst: Fr, snd: Fr, elems: &[Fr]) -> Fr {
+    let domain = (elems.len() as u64 + 2) * HASH_DOMAIN_ELEMS_BASE;
+    hash_elems_with_domain(domain, fst, snd, elems)
+}
+
+/// Hash of a 32-byte word, whose two big-endian 16-byte halves always fit
+/// the field.
+pub fn hash_byte32(word: &[u8; 32]) -> Fr {
+    let half = |bytes: &[u8]| Fr::from_u128(u128::from_be_bytes(bytes.try_into().unwrap()));
+    hash_with_domain(
+        &[half(&word[..16]), half(&word[16..])],
+        Fr::from(HASH_DOMAIN_BYTE32),
+    )
+}
+
+/// Big-endian bytes of a field element, the inverse of [`fr_from_be_bytes`].
+pub fn fr_to_be_bytes(fr: Fr) -> [u8; 32] {
+    let mut bytes = fr.to_repr();
+    bytes.reverse();
+    bytes
+}
+
+/// Secure key of a storage slot, the node key of `slot` in a [`ZkTrie`]:
+/// [`hash_byte32`] of `slot`, high half `slot[..16]` and low half
+/// `slot[16..]` read as big-endian integers. Returned as a field element
+/// and as big-endian bytes.
+pub fn hash_storage_key(slot: &[u8; 32]) -> (Fr, [u8; 32]) {
+    let key = hash_byte32(slot);
+    (key, fr_to_be_bytes(key))
+}
+
+/// Secure key of an account, [`hash_storage_key`] of `address` padded on the
+/// right with 12 zero bytes: the high half is `address[..16]`, the low half
+/// `address[16..]` followed by the padding. The padded word is also the key
+/// of the account in a [`ZkTrie`].
+pub fn hash_address_key(address: &[u8; 20]) -> (Fr, [u8; 32]) {
+    let mut word = [0u8; 32];
+    word[..20].copy_from_slice(address);
+    hash_storage_key(&word)
+}
+
+/// Field element of a big-endian 32-byte word, `None` if not canonical.
+pub fn fr_from_be_bytes(word: &[u8; 32]) -> Option<Fr> {
+    let mut repr = *word;
+    repr.reverse();
+    Fr::from_repr_vartime(repr)
+}
+
+/// Hash of a leaf value: element `i` is hashed with [`hash_byte32`] if bit
+/// `i` of `compressed_flags` is set and taken as a big-endian field element
+/// otherwise, then a single element is used as is and more are folded with
+/// [`hash_elems`].
+pub fn hash_value(compressed_flags: u32, value: &[[u8; 32]]) -> Result<Fr, ZkTrieError> {
+    let elems = value
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if index < 32 && compressed_flags & (1 << index) != 0 {
+                Ok(hash_byte32(word))
+            } else {
+                fr_from_be_bytes(word).ok_or(ZkTrieError::NonCanonicalValue { index })
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match elems.as_slice() {
+        [] => Err(ZkTrieError::EmptyValue),
+        [elem] => Ok(*elem),
+        [fst, snd, rest @ ..] => Ok(hash_elems(*fst, *snd, rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn h(a: Fr, b: Fr, domain: u64) -> Fr {
+        hash_with_domain(&[a, b], Fr::from(domain))
+    }
+
+    fn fr(hex: &str) -> Fr {
+        let mut repr = [0u8; 32];
+        for (i, byte) in repr.iter_mut().enumerate() {
+            let pos = hex.len() - 2 * (i + 1);
+            *byte = u8::from_str_radix(&hex[pos..pos + 2], 16).unwrap();
+        }
+        Fr::from_repr_vartime(repr).unwrap()
+    }
+
+    fn elems(n: u64) -> Vec<Fr> {
+        (1..=n).map(Fr::from).collect()
+    }
+
+    #[test]
+    fn test_hash_elems() {
+        let e = elems(7);
+        assert_eq!(hash_elems(e[0], e[1], &[]), h(e[0], e[1], 512));
+        assert_eq!(
+            hash_elems(e[0], e[1], &e[2..3]),
+            h(h(e[0], e[1], 768), e[2], 768)
+        );
+        let d = 4 * 256;
+        assert_eq!(
+            hash_elems(e[0], e[1], &e[2..4]),
+            h(h(e[0], e[1], d), h(e[2], e[3], d), d)
+        );
+        let d = 5 * 256;
+        assert_eq!(
+            hash_elems(e[0], e[1], &e[2..5]),
+            h(h(h(e[0], e[1], d), h(e[2], e[3], d), d), e[4], d)
+        );
+        let d = 7 * 256;
+        let base = h(e[0], e[1], d);
+        let (p0, p1, p2) = (h(e[2], e[3], d), h(e[4], e[5], d), e[6]);
+        assert_eq!(
+            hash_elems(e[0], e[1], &e[2..7]),
+            h(h(base, p0, d), h(p1, p2, d), d)
+        );
+    }
+
+    #[test]
+    fn test_hash_value() {
+        let mut word = [0u8; 32];
+        word[31] = 5;
+        word[15] = 1;
+        let byte32 = h(Fr::from(1u64), Fr::from(5u64), 512);
+        assert_eq!(hash_byte32(&word), byte32);
+
+        assert_eq!(hash_value(0, &[word]), Ok(Fr::from_raw([5, 0, 1, 0])));
+        assert_eq!(hash_value(1, &[word]), Ok(byte32));
+        assert_eq!(
+            hash_value(0b10, &[word, word, word]),
+            Ok(hash_elems(
+                fr_from_be_bytes(&word).unwrap(),
+                byte32,
+                &[fr_from_be_bytes(&word).unwrap()]
+            ))
+        );
+
+        let max = [0xff; 32];
+        assert_eq!(
+            hash_value(0b01, &[max, max]),
+            Err(ZkTrieError::NonCanonicalValue { index: 1 })
+        );
+        assert_eq!(
+            hash_value(0b11, &[max, max]),
+            Ok(hash_elems(hash_byte32(&max), hash_byte32(&max), &[]))
+        );
+        assert_eq!(hash_value(0, &[]), Err(ZkTrieError::EmptyValue));
+    }
+
+    #[test]
+    fn test_secure_keys() {
+        let slot: [u8; 32] = std::array::from_fn(|i| i as u8 + 1);
+        let high = Fr::from_u128(u128::from_be_bytes(slot[..16].try_into().unwrap()));
+        let low = Fr::from_u128(u128::from_be_bytes(slot[16..].try_into().unwrap()));
+        let (key, bytes) = hash_storage_key(&slot);
+        assert_eq!(key, h(high, low, 512));
+        assert_eq!(fr_from_be_bytes(&bytes), Some(key));
+        let mut be = key.to_repr();
+        be.reverse();
+        assert_eq!(bytes, be);
+
+        let address: [u8; 20] = slot[..20].try_into().unwrap();
+        let low = Fr::from_u128(0x11121314 << 96);
+        let (key, bytes) = hash_address_key(&address);
+        assert_eq!(key, h(high, low, 512));
+        assert_eq!(bytes, fr_to_be_bytes(key));
+    }
+
+    #[test]
+    fn test_nodes() {
+        let (left, right) = (Fr::from(11u64), Fr::from(12u64));
+        for (left_is_branch, right_is_branch, domain) in [
+            (false, false, 6),
+            (false, true, 7),
+            (true, false, 8),
+            (true, true, 9),
+        ] {
+            let branch_type = BranchType::new(left_is_branch, right_is_branch);
+            assert_eq!(branch_type as u8, domain);
+            assert_eq!(BranchType::from_u8(domain), Some(branch_type));
+            let node = BranchNode {
+                branch_type,
+                left,
+                right,
+            };
+            assert_eq!(node.hash(), h(left, right, domain as u64));
+        }
+        assert_eq!(BranchType::from_u8(5), None);
+
+        let leaf = LeafNode {
+            node_key: Fr::from(3u64),
+            compressed_flags: 1,
+            value: vec![[b'b'; 32]],
+        };
+        let value_hash = hash_byte32(&[b'b'; 32]);
+        assert_eq!(leaf.value_hash(), Ok(value_hash));
+        assert_eq!(leaf.hash(), Ok(h(Fr::from(3u64), value_hash, 4)));
+        assert!(Node::Leaf(leaf).is_terminal());
+        assert_eq!(Node::Empty(EmptyNode).hash(), Ok(Fr::zero()));
+    }
+
+    #[test]
+    fn test_regression_values() {
+        // TODO: check against vectors generated with the Go zktrie
+        // (scroll-tech/zktrie); these were computed with this implementation
+        // and only catch changes to it
+        let leaf = LeafNode {
+            node_key: fr_from_be_bytes(&[1; 32]).unwrap(),
+            compressed_flags: 0b0110,
+            value: vec![[1; 32], [b'b'; 32], [0xff; 32], [0; 32]],
+        };
+        let branch = BranchNode {
+            branch_type: BranchType::LeftTerminal,
+            left: leaf.hash().unwrap(),
+            right: Fr::one(),
+        };
+        assert_eq!(
+            leaf.value_hash(),
+            Ok(fr(
+                "271acd18f1dc15b0e84e85e4dbdfbc307910ac86291a13bef37b2708d3edb86e"
+            ))
+        );
+        assert_eq!(
+            leaf.hash(),
+            Ok(fr(
+                "21dda049d4ad0dbebd568696680e433b263ec4430a3ac28d8ed447f47ddfb6dc"
+            ))
+        );
+        assert_eq!(
+            branch.hash(),
+            fr("0240629383c9d9b3333d262f1ef75ad4434f7211a045b3fbb6b2cb08fbd54ce8")
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use super::{hash_value, ZkTrieError};
+use crate::{hash_with_domain, Fr};
+
+/// Domain of a leaf node hash, its node type.
+pub(crate) const LEAF_DOMAIN: u64 = 4;
+
+/// Branch node type, telling whether each child is a terminal (leaf or
+/// empty) node or another branch. It is the domain of the node hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum BranchType {
+    BothTerminal = 6,
+    LeftTerminal = 7,
+    RightTerminal = 8,
+    BothBranch = 9,
+}
+
+impl BranchType {
+    pub fn new(left_is_branch: bool, right_is_branch: bool) -> Self {
+        match (left_is_branch, right_is_branch) {
+            (false, false) => BranchType::BothTerminal,
+            (false, true) => BranchType::LeftTerminal,
+            (true, false) => BranchType::RightTerminal,
+            (true, true) => BranchType::BothBranch,
+        }
+    }
+
+    pub fn left_is_branch(&self) -> bool {
+        matches!(self, BranchType::RightTerminal | BranchType::BothBranch)
+    }
+
+    pub fn right_is_branch(&self) -> bool {
+        matches!(self, BranchType::LeftTerminal | BranchType::BothBranch)
+    }
+
+    pub fn from_u8(node_type: u8) -> Option<Self> {
+        match node_type {
+            6 => Some(BranchType::BothTerminal),
+            7 => Some(BranchType::LeftTerminal),
+            8 => Some(BranchType::RightTerminal),
+            9 => Some(BranchType::BothBranch),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BranchNode {
+    pub branch_type: BranchType,
+    /// Hash of the child on bit 0 of the path.
+    pub left: Fr,
+    /// Hash of the child on bit 1 of the path.
+    pub right: Fr,
+}
+
+impl BranchNode {
+    pub fn hash(&self) -> Fr {
+        hash_with_domain(&[self.left, self.right], Fr::from(self.branch_type as u64))
+    }
+}
+
+/// Leaf holding `value` under `node_key`, the hash of its key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeafNode {
+    pub node_key: Fr,
+    /// Bit `i` set if `value[i]` is hashed as a 32-byte word, see
+    /// [`hash_value`](super::hash_value).
+    pub compressed_flags: u32,
+    pub value: Vec<[u8; 32]>,
+}
+
+impl LeafNode {
+    pub fn value_hash(&self) -> Result<Fr, ZkTrieError> {
+        hash_value(self.compressed_flags, &self.value)
+    }
+
+    pub fn hash(&self) -> Result<Fr, ZkTrieError> {
+        Ok(Self::hash_with_value_hash(
+            self.node_key,
+            self.value_hash()?,
+        ))
+    }
+
+    /// Hash of a leaf with the given node key and value hash.
+    pub fn hash_with_value_hash(node_key: Fr, value_hash: Fr) -> Fr {
+        hash_with_domain(&[node_key, value_hash], Fr::from(LEAF_DOMAIN))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EmptyNode;
+
+impl EmptyNode {
+    pub fn hash(&self) -> Fr {
+        Fr::zero()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    Empty(EmptyNode),
+    Leaf(LeafNode),
+    Branch(BranchNode),
+}
+
+impl Node {
+    pub fn hash(&self) -> Result<Fr, ZkTrieError> {
+        match self {
+            Node::Empty(node) => Ok(node.hash()),
+            Node::Leaf(node) => node.hash(),
+            Node::Branch(node) => Ok(node.hash()),
+        }
+    }
+
+    /// Leaf and empty nodes are terminal, as opposed to branches.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Node::Branch(_))
+    }
+}
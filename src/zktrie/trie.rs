@@ -0,0 +1,513 @@
+use super::{hash_byte32, BranchNode, BranchType, LeafNode, Node, ZkTrieError};
+use crate::{Field, Fr, PrimeField};
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+/// Depth limit of the trie, the number of path bits in use.
+pub const MAX_LEVELS: usize = 248;
+
+/// Storage of the trie nodes by hash. The empty node, hash zero, is never
+/// stored.
+pub trait NodeStore {
+    type Error: std::error::Error;
+
+    fn get(&self, hash: &Fr) -> Result<Option<Node>, Self::Error>;
+
+    /// Stores `node` under `hash`, its hash. Nodes are never removed, so
+    /// older roots stay readable.
+    fn put(&mut self, hash: Fr, node: Node) -> Result<(), Self::Error>;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryNodeStore {
+    nodes: HashMap<[u8; 32], Node>,
+}
+
+impl MemoryNodeStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl NodeStore for MemoryNodeStore {
+    type Error = Infallible;
+
+    fn get(&self, hash: &Fr) -> Result<Option<Node>, Infallible> {
+        Ok(self.nodes.get(&hash.to_repr()).cloned())
+    }
+
+    fn put(&mut self, hash: Fr, node: Node) -> Result<(), Infallible> {
+        self.nodes.insert(hash.to_repr(), node);
+        Ok(())
+    }
+}
+
+/// Path from the root to where a key is or would be: the branches on the
+/// way, then the empty node or the leaf found at the end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub path: Vec<BranchNode>,
+    pub terminal: Node,
+}
+
+#[derive(Clone, Debug)]
+pub struct ZkTrie<S> {
+    store: S,
+    root: Fr,
+}
+
+// hash of a subtree and whether it is a branch
+type Subtree = (Fr, bool);
+
+type StoreResult<T, S> = Result<T, ZkTrieError<<S as NodeStore>::Error>>;
+
+fn path_bit(node_key: &[u8; 32], depth: usize) -> bool {
+    (node_key[depth / 8] >> (depth % 8)) & 1 == 1
+}
+
+impl<S: NodeStore> ZkTrie<S> {
+    /// Empty trie.
+    pub fn new(store: S) -> Self {
+        Self::from_root(store, Fr::zero())
+    }
+
+    /// Trie of a root already in `store`.
+    pub fn from_root(store: S, root: Fr) -> Self {
+        Self { store, root }
+    }
+
+    pub fn root(&self) -> Fr {
+        self.root
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    fn node(&self, hash: &Fr) -> StoreResult<Node, S> {
+        if hash.is_zero_vartime() {
+            return Ok(Node::Empty(Default::default()));
+        }
+        self.store
+            .get(hash)
+            .map_err(ZkTrieError::Store)?
+            .ok_or(ZkTrieError::MissingNode { hash: *hash })
+    }
+
+    fn put_branch(&mut self, left: Subtree, right: Subtree) -> StoreResult<Subtree, S> {
+        let branch = BranchNode {
+            branch_type: BranchType::new(left.1, right.1),
+            left: left.0,
+            right: right.0,
+        };
+        let hash = branch.hash();
+        self.store
+            .put(hash, Node::Branch(branch))
+            .map_err(ZkTrieError::Store)?;
+        Ok((hash, true))
+    }
+
+    pub fn get(&self, key: &[u8; 32]) -> StoreResult<Option<LeafNode>, S> {
+        match self.prove(key)?.terminal {
+            Node::Leaf(leaf) if leaf.node_key == hash_byte32(key) => Ok(Some(leaf)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Inserts or replaces the value of `key`, see [`LeafNode`].
+    pub fn update(
+        &mut self,
+        key: &[u8; 32],
+        compressed_flags: u32,
+        value: Vec<[u8; 32]>,
+    ) -> StoreResult<(), S> {
+        let leaf = LeafNode {
+            node_key: hash_byte32(key),
+            compressed_flags,
+            value,
+        };
+        let leaf_hash = leaf.hash().map_err(ZkTrieError::lift)?;
+        let node_key = leaf.node_key.to_repr();
+        let (root, _) = self.insert(self.root, &node_key, (leaf_hash, leaf), 0)?;
+        self.root = root;
+        Ok(())
+    }
+
+    fn insert(
+        &mut self,
+        hash: Fr,
+        node_key: &[u8; 32],
+        leaf: (Fr, LeafNode),
+        depth: usize,
+    ) -> StoreResult<Subtree, S> {
+        match self.node(&hash)? {
+            Node::Empty(_) => self.put_leaf(leaf),
+            Node::Leaf(old) if old.node_key == leaf.1.node_key => self.put_leaf(leaf),
+            Node::Leaf(old) => {
+                let old_key = old.node_key.to_repr();
+                self.push_leaf(node_key, leaf, (hash, &old_key), depth)
+            }
+            Node::Branch(branch) => {
+                let left = (branch.left, branch.branch_type.left_is_branch());
+                let right = (branch.right, branch.branch_type.right_is_branch());
+                if path_bit(node_key, depth) {
+                    let right = self.insert(right.0, node_key, leaf, depth + 1)?;
+                    self.put_branch(left, right)
+                } else {
+                    let left = self.insert(left.0, node_key, leaf, depth + 1)?;
+                    self.put_branch(left, right)
+                }
+            }
+        }
+    }
+
+    fn put_leaf(&mut self, (hash, leaf): (Fr, LeafNode)) -> StoreResult<Subtree, S> {
+        self.store
+            .put(hash, Node::Leaf(leaf))
+            .map_err(ZkTrieError::Store)?;
+        Ok((hash, false))
+    }
+
+    // branches down to the first path bit telling the new and the old leaf apart
+    fn push_leaf(
+        &mut self,
+        node_key: &[u8; 32],
+        leaf: (Fr, LeafNode),
+        (old_hash, old_key): (Fr, &[u8; 32]),
+        depth: usize,
+    ) -> StoreResult<Subtree, S> {
+        if depth >= MAX_LEVELS {
+            return Err(ZkTrieError::ReachedMaxLevel);
+        }
+        let empty = (Fr::zero(), false);
+        let bit = path_bit(node_key, depth);
+        if bit == path_bit(old_key, depth) {
+            let child = self.push_leaf(node_key, leaf, (old_hash, old_key), depth + 1)?;
+            if bit {
+                self.put_branch(empty, child)
+            } else {
+                self.put_branch(child, empty)
+            }
+        } else {
+            let new = self.put_leaf(leaf)?;
+            let old = (old_hash, false);
+            if bit {
+                self.put_branch(old, new)
+            } else {
+                self.put_branch(new, old)
+            }
+        }
+    }
+
+    /// Removes `key`, returns whether it was in the trie.
+    pub fn delete(&mut self, key: &[u8; 32]) -> StoreResult<bool, S> {
+        let node_key = hash_byte32(key);
+        match self.remove(self.root, &node_key, 0)? {
+            Some((root, _)) => {
+                self.root = root;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn remove(&mut self, hash: Fr, node_key: &Fr, depth: usize) -> StoreResult<Option<Subtree>, S> {
+        let branch = match self.node(&hash)? {
+            Node::Empty(_) => return Ok(None),
+            Node::Leaf(leaf) if leaf.node_key == *node_key => return Ok(Some((Fr::zero(), false))),
+            Node::Leaf(_) => return Ok(None),
+            Node::Branch(branch) => branch,
+        };
+        let left = (branch.left, branch.branch_type.left_is_branch());
+        let right = (branch.right, branch.branch_type.right_is_branch());
+        let bit = path_bit(&node_key.to_repr(), depth);
+        let (child, sibling) = if bit { (right, left) } else { (left, right) };
+        let Some(child) = self.remove(child.0, node_key, depth + 1)? else {
+            return Ok(None);
+        };
+
+        // a branch left with a single leaf is replaced by it
+        if !child.1 && !sibling.1 {
+            if child.0.is_zero_vartime() {
+                return Ok(Some(sibling));
+            }
+            if sibling.0.is_zero_vartime() {
+                return Ok(Some(child));
+            }
+        }
+        let branch = if bit {
+            self.put_branch(sibling, child)?
+        } else {
+            self.put_branch(child, sibling)?
+        };
+        Ok(Some(branch))
+    }
+
+    /// Inclusion proof of `key`, or non-inclusion proof if it is absent.
+    pub fn prove(&self, key: &[u8; 32]) -> StoreResult<Proof, S> {
+        let node_key = hash_byte32(key).to_repr();
+        let mut path = Vec::new();
+        let mut node = self.node(&self.root)?;
+        while let Node::Branch(branch) = node {
+            let child = if path_bit(&node_key, path.len()) {
+                branch.right
+            } else {
+                branch.left
+            };
+            path.push(branch);
+            node = self.node(&child)?;
+        }
+        Ok(Proof {
+            path,
+            terminal: node,
+        })
+    }
+}
+
+/// Checks `proof` of `key` against `root`: the leaf of `key` for an
+/// inclusion proof, `None` for a non-inclusion proof.
+pub fn verify_proof<'a>(
+    root: Fr,
+    key: &[u8; 32],
+    proof: &'a Proof,
+) -> Result<Option<&'a LeafNode>, ZkTrieError> {
+    let node_key = hash_byte32(key);
+    let path_key = node_key.to_repr();
+    if proof.path.len() > MAX_LEVELS || !proof.terminal.is_terminal() {
+        return Err(ZkTrieError::InvalidProof);
+    }
+
+    let mut hash = proof.terminal.hash()?;
+    let mut is_branch = false;
+    for (depth, branch) in proof.path.iter().enumerate().rev() {
+        let child = if path_bit(&path_key, depth) {
+            (branch.right, branch.branch_type.right_is_branch())
+        } else {
+            (branch.left, branch.branch_type.left_is_branch())
+        };
+        if child != (hash, is_branch) {
+            return Err(ZkTrieError::InvalidProof);
+        }
+        hash = branch.hash();
+        is_branch = true;
+    }
+    if hash != root {
+        return Err(ZkTrieError::InvalidProof);
+    }
+
+    match &proof.terminal {
+        Node::Leaf(leaf) if leaf.node_key == node_key => Ok(Some(leaf)),
+        Node::Leaf(leaf) => {
+            // another leaf proves the absence only where it sits on the path
+            let leaf_key = leaf.node_key.to_repr();
+            let on_path = (0..proof.path.len())
+                .all(|depth| path_bit(&leaf_key, depth) == path_bit(&path_key, depth));
+            if on_path {
+                Ok(None)
+            } else {
+                Err(ZkTrieError::InvalidProof)
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(i: u64) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        key[..8].copy_from_slice(&i.to_be_bytes());
+        key
+    }
+
+    fn value(i: u64) -> Vec<[u8; 32]> {
+        vec![key(i * 3 + 1), [i as u8; 32]]
+    }
+
+    // root built straight from the leaves by splitting on the path bits
+    fn reference_root(leaves: &[(Fr, Fr)], depth: usize) -> Subtree {
+        match leaves {
+            [] => (Fr::zero(), false),
+            [(_, leaf_hash)] => (*leaf_hash, false),
+            _ => {
+                let (right, left): (Vec<_>, Vec<_>) = leaves
+                    .iter()
+                    .partition(|(node_key, _)| path_bit(&node_key.to_repr(), depth));
+                let left = reference_root(&left, depth + 1);
+                let right = reference_root(&right, depth + 1);
+                let branch = BranchNode {
+                    branch_type: BranchType::new(left.1, right.1),
+                    left: left.0,
+                    right: right.0,
+                };
+                (branch.hash(), true)
+            }
+        }
+    }
+
+    fn leaves(keys: impl IntoIterator<Item = u64>) -> Vec<(Fr, Fr)> {
+        keys.into_iter()
+            .map(|i| {
+                let leaf = LeafNode {
+                    node_key: hash_byte32(&key(i)),
+                    compressed_flags: 0b10,
+                    value: value(i),
+                };
+                (leaf.node_key, leaf.hash().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_update_delete() {
+        let mut trie = ZkTrie::new(MemoryNodeStore::new());
+        assert_eq!(trie.root(), Fr::zero());
+        for i in 0..40 {
+            trie.update(&key(i), 0b10, value(i)).unwrap();
+            assert_eq!(trie.root(), reference_root(&leaves(0..=i), 0).0);
+        }
+        let full_root = trie.root();
+
+        // the root only depends on the leaves
+        let mut reversed = ZkTrie::new(MemoryNodeStore::new());
+        for i in (0..40).rev() {
+            reversed.update(&key(i), 0b10, vec![[0; 32]]).unwrap();
+            reversed.update(&key(i), 0b10, value(i)).unwrap();
+        }
+        assert_eq!(reversed.root(), full_root);
+
+        let leaf = trie.get(&key(7)).unwrap().unwrap();
+        assert_eq!(leaf.value, value(7));
+        assert_eq!(trie.get(&key(40)).unwrap(), None);
+
+        assert!(!trie.delete(&key(40)).unwrap());
+        assert_eq!(trie.root(), full_root);
+        for i in (0..40).step_by(3) {
+            assert!(trie.delete(&key(i)).unwrap());
+            assert_eq!(trie.get(&key(i)).unwrap(), None);
+        }
+        let rest: Vec<_> = (0..40).filter(|i| i % 3 != 0).collect();
+        assert_eq!(trie.root(), reference_root(&leaves(rest.clone()), 0).0);
+        for i in rest {
+            assert!(trie.delete(&key(i)).unwrap());
+        }
+        assert_eq!(trie.root(), Fr::zero());
+
+        // older roots stay in the store
+        let old = ZkTrie::from_root(trie.into_store(), full_root);
+        assert_eq!(old.get(&key(7)).unwrap(), Some(leaf));
+
+        let missing = ZkTrie::from_root(MemoryNodeStore::new(), full_root);
+        assert_eq!(
+            missing.get(&key(7)),
+            Err(ZkTrieError::MissingNode { hash: full_root })
+        );
+        let mut trie = ZkTrie::new(MemoryNodeStore::new());
+        assert_eq!(
+            trie.update(&key(1), 0, vec![[0xff; 32]]),
+            Err(ZkTrieError::NonCanonicalValue { index: 0 })
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct StoreFull;
+
+    impl std::fmt::Display for StoreFull {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "store full")
+        }
+    }
+
+    impl std::error::Error for StoreFull {}
+
+    // memory store taking a limited number of writes
+    struct BoundedStore(MemoryNodeStore, usize);
+
+    impl NodeStore for BoundedStore {
+        type Error = StoreFull;
+
+        fn get(&self, hash: &Fr) -> Result<Option<Node>, StoreFull> {
+            Ok(self.0.get(hash).unwrap())
+        }
+
+        fn put(&mut self, hash: Fr, node: Node) -> Result<(), StoreFull> {
+            self.1 = self.1.checked_sub(1).ok_or(StoreFull)?;
+            self.0.put(hash, node).unwrap();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_store_error() {
+        let mut trie = ZkTrie::new(BoundedStore(MemoryNodeStore::new(), 2));
+        trie.update(&key(1), 0b10, value(1)).unwrap();
+        let root = trie.root();
+        // a second leaf needs the leaf and at least one branch
+        assert_eq!(
+            trie.update(&key(2), 0b10, value(2)),
+            Err(ZkTrieError::Store(StoreFull))
+        );
+        assert_eq!(trie.root(), root);
+        assert_eq!(trie.get(&key(1)).unwrap().unwrap().value, value(1));
+    }
+
+    #[test]
+    fn test_proofs() {
+        let mut trie = ZkTrie::new(MemoryNodeStore::new());
+        let empty_proof = trie.prove(&key(1)).unwrap();
+        assert_eq!(verify_proof(trie.root(), &key(1), &empty_proof), Ok(None));
+        for i in 0..20 {
+            trie.update(&key(i), 0b10, value(i)).unwrap();
+        }
+        let root = trie.root();
+
+        for i in 0..30 {
+            let proof = trie.prove(&key(i)).unwrap();
+            let leaf = verify_proof(root, &key(i), &proof).unwrap();
+            assert_eq!(
+                leaf.map(|leaf| leaf.value.clone()),
+                (i < 20).then(|| value(i))
+            );
+            assert_eq!(
+                verify_proof(root + Fr::one(), &key(i), &proof),
+                Err(ZkTrieError::InvalidProof)
+            );
+        }
+
+        // a valid path to another key proves nothing about this one
+        let proof = trie.prove(&key(3)).unwrap();
+        for other in [4, 25] {
+            assert!(verify_proof(root, &key(other), &proof).is_err());
+        }
+
+        let mut proof = trie.prove(&key(3)).unwrap();
+        if let Node::Leaf(leaf) = &mut proof.terminal {
+            leaf.value[1][0] ^= 1;
+        }
+        assert_eq!(
+            verify_proof(root, &key(3), &proof),
+            Err(ZkTrieError::InvalidProof)
+        );
+
+        let mut proof = trie.prove(&key(3)).unwrap();
+        proof.terminal = Node::Branch(proof.path.pop().unwrap());
+        assert_eq!(
+            verify_proof(root, &key(3), &proof),
+            Err(ZkTrieError::InvalidProof)
+        );
+    }
+}
@@ -1,53 +1,1165 @@
-// copy from https://github.com/rust-lang/log/blob/master/src/lib.rs#L452
-
-use std::sync::atomic::Ordering;
-
-pub static mut ZKVM_HINT_HOOK: &dyn Fn([u8; 32]) = &|_| {};
-pub static STATE: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-
-#[derive(Debug)]
-pub struct SetZkvmHintHookError(pub(super) ());
-
-pub const UNINITIALIZED: usize = 0;
-pub const INITIALIZING: usize = 1;
-pub const INITIALIZED: usize = 2;
-
-// copy from https://github.com/rust-lang/log/blob/master/src/lib.rs#L1400
-#[cfg(all(feature = "zkvm-hint", target_has_atomic = "ptr"))]
-pub fn set_zkvm_hint_hook<F>(make_callback: F) -> Result<(), SetZkvmHintHookError>
-where
-    F: FnOnce() -> &'static dyn Fn([u8; 32]),
-{
-    use std::sync::atomic::Ordering;
-    match STATE.compare_exchange(
-        UNINITIALIZED,
-        INITIALIZING,
-        Ordering::Acquire,
-        Ordering::Relaxed,
-    ) {
-        Ok(UNINITIALIZED) => {
-            unsafe {
-                ZKVM_HINT_HOOK = make_callback();
-            }
-            STATE.store(INITIALIZED, Ordering::Release);
-            Ok(())
+//! Hints passed from the host to the SP1 guest.
+//!
+//! Every hash call that is not short-circuited emits one [`HintRecord`] on
+//! the host and reads one on the guest, in the same order. The record is
+//! tagged with the function and its input length, so a guest that gets out
+//! of step with the host fails loudly instead of using a wrong hash.
+//! With `zkvm-hint-batch` the guest reads the records in blocks, written
+//! with [`HintTape::to_batched_stdin_buffers`], in the same order.
+//!
+//! A record is otherwise trusted. With `zkvm-hint-verify` the guest also
+//! recomputes every hinted hash and panics on a wrong one, right after the
+//! read or, with `zkvm-hint-verify-deferred`, in [`verify_pending_hints`].
+
+use crate::{Fr, HintError, HintKind, PrimeField};
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+
+/// A hash output tagged with the call that produced it.
+///
+/// `len` is the number of input elements, or bytes for `hash_code`.
+/// Serialized as `kind: u8 || len: u64 LE || output: [u8; 32]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HintRecord {
+    pub kind: HintKind,
+    pub len: u64,
+    pub output: [u8; 32],
+}
+
+impl HintRecord {
+    pub const SIZE: usize = 1 + 8 + 32;
+
+    pub fn new(kind: HintKind, len: usize, output: [u8; 32]) -> Self {
+        Self {
+            kind,
+            len: len as u64,
+            output,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0] = self.kind as u8;
+        bytes[1..9].copy_from_slice(&self.len.to_le_bytes());
+        bytes[9..].copy_from_slice(&self.output);
+        bytes
+    }
+
+    /// `None` if `bytes` is not a serialized record.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
         }
-        Err(INITIALIZING) => {
-            while STATE.load(Ordering::Relaxed) == INITIALIZING {
-                std::hint::spin_loop();
+        Some(Self {
+            kind: HintKind::from_u8(bytes[0])?,
+            len: u64::from_le_bytes(bytes[1..9].try_into().unwrap()),
+            output: bytes[9..].try_into().unwrap(),
+        })
+    }
+
+    /// Output of the record, panics if it was not produced by `kind` over `len` inputs.
+    pub fn expect(&self, kind: HintKind, len: usize) -> [u8; 32] {
+        assert!(
+            self.kind == kind && self.len == len as u64,
+            "zkvm hint mismatch: expected {kind}(len {len}), got {}(len {})",
+            self.kind,
+            self.len
+        );
+        self.output
+    }
+}
+
+/// A hinted call with its inputs, for auditing on the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HintEvent<'a> {
+    HashWithDomain {
+        inp: &'a [Fr; 2],
+        domain: Fr,
+        output: Fr,
+    },
+    HashMsg {
+        msg: &'a [Fr],
+        cap: Option<u128>,
+        output: Fr,
+    },
+    HashCode {
+        code_len: usize,
+        output: [u8; 32],
+    },
+}
+
+impl HintEvent<'_> {
+    pub fn kind(&self) -> HintKind {
+        match self {
+            HintEvent::HashWithDomain { .. } => HintKind::HashWithDomain,
+            HintEvent::HashMsg { .. } => HintKind::HashMsg,
+            HintEvent::HashCode { .. } => HintKind::HashCode,
+        }
+    }
+
+    /// The record the guest reads for this call.
+    pub fn record(&self) -> HintRecord {
+        match self {
+            HintEvent::HashWithDomain { inp, output, .. } => {
+                HintRecord::new(self.kind(), inp.len(), output.to_repr())
             }
+            HintEvent::HashMsg { msg, output, .. } => {
+                HintRecord::new(self.kind(), msg.len(), output.to_repr())
+            }
+            HintEvent::HashCode { code_len, output } => {
+                HintRecord::new(self.kind(), *code_len, *output)
+            }
+        }
+    }
+}
+
+/// Hint records in the order the guest reads them.
+///
+/// The file format is little-endian:
+///
+/// ```text
+/// magic   b"PHTAPE"
+/// version u16, currently 1
+/// count   u64
+/// count records of HintRecord::SIZE bytes: kind u8 || len u64 || output [u8; 32]
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HintTape {
+    records: Vec<HintRecord>,
+}
+
+impl HintTape {
+    const MAGIC: &'static [u8; 6] = b"PHTAPE";
+    const VERSION: u16 = 1;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, record: HintRecord) {
+        self.records.push(record);
+    }
+
+    pub fn records(&self) -> &[HintRecord] {
+        &self.records
+    }
+
+    /// Runs `f`, appending the hints of its calls on this thread to the tape.
+    #[cfg(not(target_os = "zkvm"))]
+    pub fn record<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let records = std::cell::RefCell::new(&mut self.records);
+        with_hint_sink(|event| records.borrow_mut().push(event.record()), f)
+    }
+
+    /// One buffer per hint, to be written to the guest stdin in order so
+    /// that each `read_vec` gets the next one.
+    pub fn to_stdin_buffers(&self) -> Vec<Vec<u8>> {
+        self.records
+            .iter()
+            .map(|record| record.to_bytes().to_vec())
+            .collect()
+    }
+
+    /// Buffers of up to `block_len` hints each, for a guest built with
+    /// `zkvm-hint-batch`. The guest reads a block only once it has used the
+    /// previous one, so the order is the same as with [`Self::to_stdin_buffers`].
+    pub fn to_batched_stdin_buffers(&self, block_len: usize) -> Vec<Vec<u8>> {
+        assert!(block_len != 0, "empty hint blocks");
+        self.records
+            .chunks(block_len)
+            .map(|block| block.iter().flat_map(|record| record.to_bytes()).collect())
+            .collect()
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(Self::MAGIC)?;
+        writer.write_all(&Self::VERSION.to_le_bytes())?;
+        writer.write_all(&(self.records.len() as u64).to_le_bytes())?;
+        for record in &self.records {
+            writer.write_all(&record.to_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let mut magic = [0u8; 6];
+        reader.read_exact(&mut magic)?;
+        if &magic != Self::MAGIC {
+            return Err(invalid("not a hint tape".to_string()));
+        }
+        let mut version = [0u8; 2];
+        reader.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version != Self::VERSION {
+            return Err(invalid(format!("unsupported hint tape version {version}")));
+        }
+        let mut count = [0u8; 8];
+        reader.read_exact(&mut count)?;
+        let count = u64::from_le_bytes(count);
+
+        let mut records = Vec::new();
+        let mut bytes = [0u8; HintRecord::SIZE];
+        for i in 0..count {
+            reader.read_exact(&mut bytes)?;
+            let record = HintRecord::from_bytes(&bytes)
+                .ok_or_else(|| invalid(format!("invalid hint record {i}")))?;
+            records.push(record);
+        }
+        Ok(Self { records })
+    }
+}
+
+/// Output of the serialized record `bytes`, the hint `index` read by a
+/// call of `kind` over `len` inputs.
+pub(crate) fn parse_hint(
+    index: usize,
+    kind: HintKind,
+    len: usize,
+    bytes: &[u8],
+) -> Result<[u8; 32], HintError> {
+    if bytes.len() != HintRecord::SIZE {
+        return Err(HintError::WrongLength {
+            index,
+            kind,
+            len,
+            bytes: bytes.len(),
+        });
+    }
+    let found_len = u64::from_le_bytes(bytes[1..9].try_into().unwrap());
+    if bytes[0] != kind as u8 || found_len != len as u64 {
+        return Err(HintError::TagMismatch {
+            index,
+            kind,
+            len,
+            found_kind: bytes[0],
+            found_len,
+        });
+    }
+    Ok(bytes[9..].try_into().unwrap())
+}
+
+/// Hinted output of `hash_with_domain` or `hash_msg` as a field element.
+#[cfg_attr(
+    not(any(target_os = "zkvm", feature = "zkvm-hint-replay")),
+    allow(dead_code)
+)]
+pub(crate) fn decode_fr(
+    index: usize,
+    kind: HintKind,
+    len: usize,
+    output: [u8; 32],
+) -> Result<Fr, HintError> {
+    Fr::from_repr_vartime(output).ok_or(HintError::NonCanonical { index, kind, len })
+}
+
+/// Where the guest reads the buffers the host wrote for it, in order.
+///
+/// The hash functions of a guest read through the source set with
+/// `set_hint_source`, by default [`Sp1HintSource`] on SP1. Another zkvm
+/// only needs an adapter to its stdin, e.g. `env::read` on RISC Zero.
+pub trait HintSource {
+    /// Next buffer, `None` if the host wrote no more.
+    fn next_buffer(&mut self) -> Option<Vec<u8>>;
+}
+
+impl<S: HintSource + ?Sized> HintSource for Box<S> {
+    fn next_buffer(&mut self) -> Option<Vec<u8>> {
+        (**self).next_buffer()
+    }
+}
+
+/// SP1 guest stdin.
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sp1HintSource;
+
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
+impl HintSource for Sp1HintSource {
+    fn next_buffer(&mut self) -> Option<Vec<u8>> {
+        Some(sp1_lib::io::read_vec())
+    }
+}
+
+/// Buffers held in memory, e.g. from [`HintTape::to_stdin_buffers`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryHintSource {
+    buffers: VecDeque<Vec<u8>>,
+}
+
+impl MemoryHintSource {
+    pub fn new(buffers: impl IntoIterator<Item = Vec<u8>>) -> Self {
+        Self {
+            buffers: buffers.into_iter().collect(),
+        }
+    }
+}
+
+impl HintSource for MemoryHintSource {
+    fn next_buffer(&mut self) -> Option<Vec<u8>> {
+        self.buffers.pop_front()
+    }
+}
+
+/// Guest side of the stdin protocol: every buffer holds one record or,
+/// `batched`, a block of records that later reads are served from.
+#[cfg_attr(not(target_os = "zkvm"), allow(dead_code))]
+pub(crate) struct HintReader<S> {
+    source: S,
+    batched: bool,
+    block: Vec<u8>,
+    pos: usize,
+    next_index: usize,
+}
+
+#[cfg_attr(not(target_os = "zkvm"), allow(dead_code))]
+impl<S: HintSource> HintReader<S> {
+    pub(crate) fn new(source: S, batched: bool) -> Self {
+        Self {
+            source,
+            batched,
+            block: Vec::new(),
+            pos: 0,
+            next_index: 0,
+        }
+    }
+
+    /// Next hint, which must come from `kind` over `len` inputs, with its index.
+    pub(crate) fn read(
+        &mut self,
+        kind: HintKind,
+        len: usize,
+    ) -> Result<(usize, [u8; 32]), HintError> {
+        let index = self.next_index;
+        self.next_index += 1;
+        let exhausted = HintError::Exhausted { index, kind, len };
+        let output = if self.batched {
+            if self.pos == self.block.len() {
+                self.block = self.source.next_buffer().ok_or(exhausted)?;
+                self.pos = 0;
+            }
+            // a truncated record is reported with the bytes left in the block
+            let end = self.block.len().min(self.pos + HintRecord::SIZE);
+            let bytes = &self.block[self.pos..end];
+            self.pos = end;
+            parse_hint(index, kind, len, bytes)
+        } else {
+            let bytes = self.source.next_buffer().ok_or(exhausted)?;
+            parse_hint(index, kind, len, &bytes)
+        };
+        output.map(|output| (index, output))
+    }
+}
+
+#[cfg(target_os = "zkvm")]
+pub use guest::*;
+
+#[cfg(target_os = "zkvm")]
+mod guest {
+    use super::{HintReader, HintSource};
+    use crate::{HintError, HintKind};
+    use std::cell::RefCell;
+
+    type Reader = HintReader<Box<dyn HintSource>>;
+
+    thread_local! {
+        // created on the first read, or by `set_hint_source`
+        static READER: RefCell<Option<Reader>> = const { RefCell::new(None) };
+    }
+
+    fn default_source() -> Box<dyn HintSource> {
+        #[cfg(target_vendor = "succinct")]
+        return Box::new(super::Sp1HintSource);
+        #[cfg(not(target_vendor = "succinct"))]
+        panic!("no zkvm hint source for this target, call set_hint_source first");
+    }
+
+    /// Makes the hash functions read their hints from `source`.
+    ///
+    /// Hints left in a block already read are still used first, and the
+    /// hint index keeps counting.
+    pub fn set_hint_source(source: impl HintSource + 'static) {
+        let source: Box<dyn HintSource> = Box::new(source);
+        READER.with(|reader| match &mut *reader.borrow_mut() {
+            Some(reader) => reader.source = source,
+            reader => *reader = Some(HintReader::new(source, cfg!(feature = "zkvm-hint-batch"))),
+        });
+    }
+
+    pub(crate) fn read(kind: HintKind, len: usize) -> Result<(usize, [u8; 32]), HintError> {
+        READER.with(|reader| {
+            reader
+                .borrow_mut()
+                .get_or_insert_with(|| {
+                    HintReader::new(default_source(), cfg!(feature = "zkvm-hint-batch"))
+                })
+                .read(kind, len)
+        })
+    }
+}
+
+#[cfg(not(target_os = "zkvm"))]
+pub use host::*;
+
+#[cfg(not(target_os = "zkvm"))]
+mod host {
+    use super::{HintEvent, HintRecord};
+    use std::cell::RefCell;
+    use std::sync::OnceLock;
+
+    type Hook = &'static (dyn Fn(&HintEvent) + Send + Sync);
+    type Sink = *const (dyn Fn(&HintEvent) + 'static);
+
+    static ZKVM_HINT_HOOK: OnceLock<Hook> = OnceLock::new();
+
+    thread_local! {
+        // innermost sink last, see `with_hint_sink` for why the lifetime is erased
+        static SINKS: RefCell<Vec<Sink>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[derive(Debug)]
+    pub struct SetZkvmHintHookError(pub(crate) ());
+
+    /// Registers a process-wide hook that is given the output of each hinted
+    /// call. Only one global hook of any kind can be set.
+    pub fn set_zkvm_hint_hook<F>(make_callback: F) -> Result<(), SetZkvmHintHookError>
+    where
+        F: FnOnce() -> &'static (dyn Fn([u8; 32]) + Send + Sync),
+    {
+        set_zkvm_hint_event_hook(|| {
+            let callback = make_callback();
+            Box::leak(Box::new(move |event: &HintEvent| {
+                callback(event.record().output)
+            }))
+        })
+    }
+
+    /// Registers a process-wide hook that only sees the [`HintRecord`] of
+    /// each call, what the guest reads.
+    pub fn set_zkvm_hint_record_hook<F>(make_callback: F) -> Result<(), SetZkvmHintHookError>
+    where
+        F: FnOnce() -> &'static (dyn Fn(HintRecord) + Send + Sync),
+    {
+        set_zkvm_hint_event_hook(|| {
+            let callback = make_callback();
+            Box::leak(Box::new(move |event: &HintEvent| callback(event.record())))
+        })
+    }
+
+    /// Registers a process-wide hook that sees every hinted call with its
+    /// inputs. It is the fallback for threads without a [`with_hint_sink`].
+    pub fn set_zkvm_hint_event_hook<F>(make_callback: F) -> Result<(), SetZkvmHintHookError>
+    where
+        F: FnOnce() -> Hook,
+    {
+        let mut set = false;
+        ZKVM_HINT_HOOK.get_or_init(|| {
+            set = true;
+            make_callback()
+        });
+        if set {
+            Ok(())
+        } else {
             Err(SetZkvmHintHookError(()))
         }
-        _ => Err(SetZkvmHintHookError(())),
+    }
+
+    /// Sends the hints of the calls made by `f` on this thread to `sink`.
+    ///
+    /// Sinks nest: only the innermost one sees a hint. Outside of any sink
+    /// the global hook is used.
+    pub fn with_hint_sink<S, F, R>(sink: S, f: F) -> R
+    where
+        S: Fn(&HintEvent),
+        F: FnOnce() -> R,
+    {
+        struct Pop;
+        impl Drop for Pop {
+            fn drop(&mut self) {
+                SINKS.with(|sinks| sinks.borrow_mut().pop());
+            }
+        }
+
+        let sink: &dyn Fn(&HintEvent) = &sink;
+        // SAFETY: the pointer is popped by `Pop`, also on unwind, before
+        // `sink` is dropped, so it is never dereferenced after that
+        let sink = unsafe { std::mem::transmute::<*const dyn Fn(&HintEvent), Sink>(sink) };
+        SINKS.with(|sinks| sinks.borrow_mut().push(sink));
+        let _pop = Pop;
+        f()
+    }
+
+    #[inline]
+    pub fn hint(event: HintEvent) {
+        match SINKS.with(|sinks| sinks.borrow().last().copied()) {
+            // SAFETY: see `with_hint_sink`
+            Some(sink) => unsafe { (*sink)(&event) },
+            None => {
+                if let Some(hook) = ZKVM_HINT_HOOK.get() {
+                    hook(&event);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    not(target_os = "zkvm"),
+    not(target_vendor = "succinct"),
+    feature = "zkvm-hint-replay"
+))]
+pub use replay::*;
+
+#[cfg(all(
+    not(target_os = "zkvm"),
+    not(target_vendor = "succinct"),
+    feature = "zkvm-hint-replay"
+))]
+mod replay {
+    use super::{parse_hint, HintRecord, HintTape};
+    use crate::{HintError, HintKind};
+    use std::cell::RefCell;
+
+    /// First hint whose recomputed value differs from the tape.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct HintDivergence {
+        pub index: usize,
+        pub record: HintRecord,
+        pub computed: [u8; 32],
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct ReplayReport {
+        pub consumed: usize,
+        /// Records left on the tape, the guest would leave them unread.
+        pub remaining: usize,
+        pub first_divergence: Option<HintDivergence>,
+    }
+
+    struct Replay {
+        records: Vec<HintRecord>,
+        recompute: bool,
+        report: ReplayReport,
+    }
+
+    thread_local! {
+        static REPLAY: RefCell<Option<Replay>> = const { RefCell::new(None) };
+    }
+
+    /// Runs `f` with the hash functions of this thread reading their results
+    /// from `tape` in order, like the guest does, instead of computing them.
+    ///
+    /// A call that does not match the next record panics like the guest. With
+    /// `recompute`, every result is also computed and the first one differing
+    /// from the tape is reported; the tape value is still returned.
+    pub fn replay_hints<R>(
+        tape: &HintTape,
+        recompute: bool,
+        f: impl FnOnce() -> R,
+    ) -> (R, ReplayReport) {
+        // puts back the outer replay, also on unwind
+        struct Restore(Option<Option<Replay>>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(outer) = self.0.take() {
+                    REPLAY.with(|replay| *replay.borrow_mut() = outer);
+                }
+            }
+        }
+
+        let replay = Replay {
+            records: tape.records().to_vec(),
+            recompute,
+            report: ReplayReport::default(),
+        };
+        let outer = REPLAY.with(|cell| cell.borrow_mut().replace(replay));
+        let mut restore = Restore(Some(outer));
+
+        let result = f();
+
+        let outer = restore.0.take().unwrap();
+        let replay = REPLAY.with(|cell| std::mem::replace(&mut *cell.borrow_mut(), outer));
+        let mut report = replay.unwrap().report;
+        report.remaining = tape.records().len() - report.consumed;
+        (result, report)
+    }
+
+    /// Next hinted output with its index if a replay is running on this thread.
+    pub(crate) fn replay(
+        kind: HintKind,
+        len: usize,
+        compute: impl FnOnce() -> [u8; 32],
+    ) -> Option<Result<(usize, [u8; 32]), HintError>> {
+        let (index, record, recompute) = REPLAY.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let replay = cell.as_mut()?;
+            let index = replay.report.consumed;
+            let record = replay.records.get(index).copied();
+            replay.report.consumed += record.is_some() as usize;
+            Some((index, record, replay.recompute))
+        })?;
+
+        let Some(record) = record else {
+            return Some(Err(HintError::Exhausted { index, kind, len }));
+        };
+        let output = match parse_hint(index, kind, len, &record.to_bytes()) {
+            Ok(output) => output,
+            Err(err) => return Some(Err(err)),
+        };
+        if recompute {
+            let computed = compute();
+            if computed != output {
+                REPLAY.with(|cell| {
+                    let mut cell = cell.borrow_mut();
+                    let report = &mut cell.as_mut().unwrap().report;
+                    report.first_divergence.get_or_insert(HintDivergence {
+                        index,
+                        record,
+                        computed,
+                    });
+                });
+            }
+        }
+        Some(Ok((index, output)))
+    }
+}
+
+#[cfg(feature = "zkvm-hint-verify")]
+pub use verify::*;
+
+#[cfg(feature = "zkvm-hint-verify")]
+// only the guest reads hints, the host uses it in tests
+#[cfg_attr(not(target_os = "zkvm"), allow(dead_code))]
+mod verify {
+    use crate::{compute_code_hash, Fr, HintKind, POSEIDON};
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+
+    /// A hinted call to check. The inputs are only copied when it is queued.
+    #[allow(clippy::enum_variant_names)]
+    pub(crate) enum PendingHint<'a> {
+        HashWithDomain {
+            inp: [Fr; 2],
+            domain: Fr,
+            output: Fr,
+        },
+        HashMsg {
+            msg: Cow<'a, [Fr]>,
+            cap: Option<u128>,
+            output: Fr,
+        },
+        HashCode {
+            code: Cow<'a, [u8]>,
+            output: [u8; 32],
+        },
+    }
+
+    impl PendingHint<'_> {
+        fn into_owned(self) -> PendingHint<'static> {
+            match self {
+                PendingHint::HashWithDomain {
+                    inp,
+                    domain,
+                    output,
+                } => PendingHint::HashWithDomain {
+                    inp,
+                    domain,
+                    output,
+                },
+                PendingHint::HashMsg { msg, cap, output } => PendingHint::HashMsg {
+                    msg: Cow::Owned(msg.into_owned()),
+                    cap,
+                    output,
+                },
+                PendingHint::HashCode { code, output } => PendingHint::HashCode {
+                    code: Cow::Owned(code.into_owned()),
+                    output,
+                },
+            }
+        }
+
+        fn check(&self, index: usize) {
+            let (kind, len, holds) = match self {
+                PendingHint::HashWithDomain {
+                    inp,
+                    domain,
+                    output,
+                } => (
+                    HintKind::HashWithDomain,
+                    inp.len(),
+                    POSEIDON.hash_with_domain(inp, *domain) == *output,
+                ),
+                PendingHint::HashMsg { msg, cap, output } => (
+                    HintKind::HashMsg,
+                    msg.len(),
+                    POSEIDON.hash_msg(msg, *cap) == *output,
+                ),
+                PendingHint::HashCode { code, output } => (
+                    HintKind::HashCode,
+                    code.len(),
+                    compute_code_hash(code) == *output,
+                ),
+            };
+            assert!(
+                holds,
+                "zkvm hint {index} for {kind}(len {len}) does not match the recomputed hash"
+            );
+        }
+    }
+
+    thread_local! {
+        static PENDING: RefCell<Vec<(usize, PendingHint<'static>)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    /// Checks the hint `index` read by the guest now, or queues it with
+    /// `zkvm-hint-verify-deferred`.
+    pub(crate) fn verify_hint(index: usize, hint: PendingHint<'_>) {
+        if cfg!(feature = "zkvm-hint-verify-deferred") {
+            let hint = hint.into_owned();
+            PENDING.with(|pending| pending.borrow_mut().push((index, hint)));
+        } else {
+            hint.check(index);
+        }
+    }
+
+    /// Recomputes every hint queued since the last call and panics on the
+    /// first wrong one.
+    ///
+    /// With `zkvm-hint-verify-deferred` the hints are only sound once this
+    /// has run, so the guest must call it before committing its outputs.
+    /// Otherwise there is nothing queued and this does nothing.
+    pub fn verify_pending_hints() {
+        let pending = PENDING.with(|pending| pending.take());
+        for (index, hint) in &pending {
+            hint.check(*index);
+        }
     }
 }
 
-#[inline]
-pub fn hint(result: [u8; 32]) {
-    let hook = if STATE.load(Ordering::Acquire) != INITIALIZED {
-        &|_| {}
-    } else {
-        unsafe { ZKVM_HINT_HOOK }
-    };
-    hook(result);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+    use crate::{hash_code, hash_msg, hash_with_domain};
+    #[cfg(feature = "zkvm-hint-replay")]
+    use crate::{try_hash_code, try_hash_msg};
+    use std::cell::RefCell;
+    use std::panic::AssertUnwindSafe;
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    #[test]
+    fn test_record_bytes() {
+        let record = HintRecord::new(HintKind::HashMsg, 10, [7u8; 32]);
+        let bytes = record.to_bytes();
+        assert_eq!(HintRecord::from_bytes(&bytes), Some(record));
+        assert_eq!(HintRecord::from_bytes(&bytes[1..]), None);
+
+        let mut bad_kind = bytes;
+        bad_kind[0] = 0;
+        assert_eq!(HintRecord::from_bytes(&bad_kind), None);
+
+        assert_eq!(record.expect(HintKind::HashMsg, 10), [7u8; 32]);
+        let mismatch = std::panic::catch_unwind(|| record.expect(HintKind::HashCode, 10));
+        assert!(mismatch.is_err());
+    }
+
+    #[test]
+    fn test_parse_hint() {
+        let record = HintRecord::new(HintKind::HashMsg, 10, [7u8; 32]);
+        let bytes = record.to_bytes();
+        assert_eq!(parse_hint(4, HintKind::HashMsg, 10, &bytes), Ok([7u8; 32]));
+
+        let err = parse_hint(4, HintKind::HashMsg, 10, &bytes[..32]).unwrap_err();
+        assert_eq!(
+            err,
+            HintError::WrongLength {
+                index: 4,
+                kind: HintKind::HashMsg,
+                len: 10,
+                bytes: 32,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "zkvm hint 4 for hash_msg(len 10): malformed record of 32 bytes"
+        );
+
+        let err = parse_hint(4, HintKind::HashCode, 10, &bytes).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "zkvm hint 4 for hash_code(len 10): tag mismatch, got hash_msg(len 10)"
+        );
+        let mut bad_kind = bytes;
+        bad_kind[0] = 9;
+        let err = parse_hint(4, HintKind::HashMsg, 10, &bad_kind).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "zkvm hint 4 for hash_msg(len 10): tag mismatch, got unknown kind 9"
+        );
+
+        let err = decode_fr(4, HintKind::HashMsg, 10, [0xff; 32]).unwrap_err();
+        assert_eq!(
+            err,
+            HintError::NonCanonical {
+                index: 4,
+                kind: HintKind::HashMsg,
+                len: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_batched_reader() {
+        let mut tape = HintTape::new();
+        tape.record(|| {
+            for i in 0..10u64 {
+                hash_msg(&vec![Fr::from(i); i as usize + 1], None);
+                hash_with_domain(&[Fr::from(i), Fr::zero()], Fr::one());
+                hash_code(&vec![i as u8; i as usize * 7 + 1]);
+            }
+        });
+        let records = tape.records();
+
+        let serve = |buffers: Vec<Vec<u8>>, batched: bool| {
+            let mut reader = HintReader::new(MemoryHintSource::new(buffers), batched);
+            records
+                .iter()
+                .map(|record| reader.read(record.kind, record.len as usize))
+                .collect::<Vec<_>>()
+        };
+        let expected: Vec<_> = records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| Ok((i, record.output)))
+            .collect();
+        assert_eq!(serve(tape.to_stdin_buffers(), false), expected);
+        for block_len in [1, 2, 7, records.len(), 100] {
+            let buffers = tape.to_batched_stdin_buffers(block_len);
+            assert_eq!(buffers.len(), records.len().div_ceil(block_len));
+            assert_eq!(serve(buffers, true), expected);
+        }
+
+        // a block is not a record, and a truncated record is not skipped
+        let result = serve(tape.to_batched_stdin_buffers(2), false);
+        assert!(matches!(
+            result[0],
+            Err(HintError::WrongLength { bytes: 82, .. })
+        ));
+        let mut buffers = tape.to_batched_stdin_buffers(2);
+        buffers[0].pop();
+        let result = serve(buffers, true);
+        assert_eq!(result[0], expected[0]);
+        assert!(matches!(
+            result[1],
+            Err(HintError::WrongLength {
+                index: 1,
+                bytes: 40,
+                ..
+            })
+        ));
+        assert_eq!(result[2], expected[2]);
+
+        let mut reader = HintReader::new(MemoryHintSource::new(tape.to_stdin_buffers()), true);
+        reader.source.buffers.truncate(1);
+        assert_eq!(reader.read(HintKind::HashMsg, 1), expected[0]);
+        assert_eq!(
+            reader.read(HintKind::HashWithDomain, 2),
+            Err(HintError::Exhausted {
+                index: 1,
+                kind: HintKind::HashWithDomain,
+                len: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_event_record() {
+        let msg = [Fr::one(); 3];
+        let event = HintEvent::HashMsg {
+            msg: &msg,
+            cap: None,
+            output: Fr::from(5u64),
+        };
+        assert_eq!(event.kind(), HintKind::HashMsg);
+        assert_eq!(
+            event.record(),
+            HintRecord::new(HintKind::HashMsg, 3, Fr::from(5u64).to_repr())
+        );
+
+        let event = HintEvent::HashCode {
+            code_len: 40,
+            output: [1u8; 32],
+        };
+        assert_eq!(
+            event.record(),
+            HintRecord::new(HintKind::HashCode, 40, [1u8; 32])
+        );
+    }
+
+    #[test]
+    fn test_one_hint_per_call() {
+        // also covers the record hook adapter
+        // other tests hash concurrently, only keep the hints of this thread
+        static RECORDS: Mutex<Vec<(ThreadId, HintRecord)>> = Mutex::new(Vec::new());
+        set_zkvm_hint_record_hook(|| {
+            &|record| {
+                RECORDS
+                    .lock()
+                    .unwrap()
+                    .push((thread::current().id(), record))
+            }
+        })
+        .unwrap();
+        let take = || -> Vec<HintRecord> {
+            let mut records = RECORDS.lock().unwrap();
+            let id = thread::current().id();
+            let mine = records.iter().filter(|(t, _)| *t == id).map(|(_, r)| *r);
+            let mine = mine.collect();
+            records.retain(|(t, _)| *t != id);
+            mine
+        };
+
+        // short-circuited calls read no hint on the guest, so none is emitted
+        hash_with_domain(&[Fr::zero(), Fr::zero()], Fr::zero());
+        hash_msg(&[], None);
+        hash_code(&[]);
+        assert_eq!(take(), vec![]);
+
+        let hash = hash_with_domain(&[Fr::one(), Fr::zero()], Fr::zero());
+        assert_eq!(
+            take(),
+            vec![HintRecord::new(HintKind::HashWithDomain, 2, hash.to_repr())]
+        );
+
+        let msg = [Fr::one(); 5];
+        let hash = hash_msg(&msg, Some(3));
+        assert_eq!(
+            take(),
+            vec![HintRecord::new(HintKind::HashMsg, 5, hash.to_repr())]
+        );
+
+        // a single chunk of code is still one hash_code hint
+        for len in [1, 31, 32, 100] {
+            let code = vec![1u8; len];
+            let hash = hash_code(&code);
+            assert_eq!(take(), vec![HintRecord::new(HintKind::HashCode, len, hash)]);
+        }
+    }
+
+    #[test]
+    fn test_hint_tape() {
+        let mut tape = HintTape::new();
+        let (a, b) = tape.record(|| {
+            let a = hash_msg(&[Fr::one(); 3], None);
+            hash_with_domain(&[Fr::zero(), Fr::zero()], Fr::zero());
+            let b = hash_code(&[1u8; 40]);
+            (a, b)
+        });
+        assert_eq!(
+            tape.records(),
+            &[
+                HintRecord::new(HintKind::HashMsg, 3, a.to_repr()),
+                HintRecord::new(HintKind::HashCode, 40, b),
+            ]
+        );
+        assert_eq!(
+            tape.to_stdin_buffers(),
+            vec![
+                tape.records()[0].to_bytes().to_vec(),
+                tape.records()[1].to_bytes().to_vec(),
+            ]
+        );
+
+        let mut file = vec![];
+        tape.write_to(&mut file).unwrap();
+        assert_eq!(file.len(), 6 + 2 + 8 + 2 * HintRecord::SIZE);
+        assert_eq!(HintTape::read_from(&file[..]).unwrap(), tape);
+
+        assert!(HintTape::read_from(&file[..file.len() - 1]).is_err());
+        let mut bad_magic = file.clone();
+        bad_magic[0] ^= 1;
+        assert!(HintTape::read_from(&bad_magic[..]).is_err());
+        let mut bad_kind = file.clone();
+        bad_kind[16] = 9;
+        assert!(HintTape::read_from(&bad_kind[..]).is_err());
+    }
+
+    #[cfg(feature = "zkvm-hint-replay")]
+    #[test]
+    fn test_replay() {
+        let msg = [Fr::one(); 3];
+        let code = [1u8; 40];
+        let calls = || {
+            (
+                hash_msg(&msg, None),
+                hash_with_domain(&[Fr::zero(), Fr::zero()], Fr::zero()),
+                hash_code(&code),
+            )
+        };
+        let mut tape = HintTape::new();
+        let expected = tape.record(calls);
+
+        let (result, report) = replay_hints(&tape, true, calls);
+        assert_eq!(result, expected);
+        assert_eq!(
+            report,
+            ReplayReport {
+                consumed: 2,
+                remaining: 0,
+                first_divergence: None,
+            }
+        );
+
+        // the tape value wins, the recomputed one is reported
+        let mut forged = HintTape::new();
+        forged.push(tape.records()[0]);
+        let mut record = tape.records()[1];
+        record.output[0] ^= 1;
+        forged.push(record);
+        forged.push(tape.records()[1]);
+        let (result, report) = replay_hints(&forged, true, calls);
+        assert_eq!(result.2, record.output);
+        assert_eq!(report.remaining, 1);
+        assert_eq!(
+            report.first_divergence,
+            Some(HintDivergence {
+                index: 1,
+                record,
+                computed: expected.2,
+            })
+        );
+        let (_, report) = replay_hints(&forged, false, calls);
+        assert_eq!(report.first_divergence, None);
+
+        // out of order calls fail like the guest
+        let swapped = || (hash_code(&code), hash_msg(&msg, None));
+        let result = std::panic::catch_unwind(|| replay_hints(&tape, false, swapped));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(|| replay_hints(&HintTape::new(), false, calls));
+        assert!(result.is_err());
+
+        // outside of a replay the functions compute again
+        assert_eq!(calls(), expected);
+
+        let mut bad = HintTape::new();
+        bad.push(HintRecord::new(HintKind::HashMsg, 3, [0xff; 32]));
+        let (result, _) = replay_hints(&bad, false, || {
+            (try_hash_msg(&msg, None), try_hash_code(&code))
+        });
+        assert_eq!(
+            result,
+            (
+                Err(HintError::NonCanonical {
+                    index: 0,
+                    kind: HintKind::HashMsg,
+                    len: 3,
+                }),
+                Err(HintError::Exhausted {
+                    index: 1,
+                    kind: HintKind::HashCode,
+                    len: 40,
+                })
+            )
+        );
+        let err = std::panic::catch_unwind(|| replay_hints(&bad, false, || hash_msg(&msg, None)))
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<String>().unwrap(),
+            "zkvm hint 0 for hash_msg(len 3): non-canonical field element"
+        );
+    }
+
+    #[cfg(feature = "zkvm-hint-verify")]
+    #[test]
+    fn test_verify_hints() {
+        let inp = [Fr::one(), Fr::zero()];
+        let msg = [Fr::one(); 3];
+        let code = [1u8; 40];
+        let hints = |tamper: bool| {
+            let mut code_hash = hash_code(&code);
+            code_hash[0] ^= tamper as u8;
+            [
+                PendingHint::HashWithDomain {
+                    inp,
+                    domain: Fr::one(),
+                    output: hash_with_domain(&inp, Fr::one()),
+                },
+                PendingHint::HashMsg {
+                    msg: msg[..].into(),
+                    cap: None,
+                    output: hash_msg(&msg, None),
+                },
+                PendingHint::HashCode {
+                    code: code[..].into(),
+                    output: code_hash,
+                },
+            ]
+        };
+
+        hints(false)
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, hint)| verify_hint(index, hint));
+        verify_pending_hints();
+
+        let err = std::panic::catch_unwind(|| {
+            hints(true)
+                .into_iter()
+                .enumerate()
+                .for_each(|(index, hint)| verify_hint(index + 3, hint));
+            verify_pending_hints();
+        })
+        .unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(
+            msg.starts_with("zkvm hint 5 for hash_code(len 40)"),
+            "{msg}"
+        );
+
+        // the queue is drained even when a check fails
+        verify_pending_hints();
+    }
+
+    #[test]
+    fn test_hint_sinks() {
+        let hash = |n: u64| hash_msg(&[Fr::from(n)], None);
+        let outer = RefCell::new(vec![]);
+        let inner = RefCell::new(vec![]);
+        fn push(sink: &RefCell<Vec<Fr>>) -> impl Fn(&HintEvent) + '_ {
+            move |event| match event {
+                HintEvent::HashMsg { output, .. } => sink.borrow_mut().push(*output),
+                _ => unreachable!(),
+            }
+        }
+
+        with_hint_sink(push(&outer), || {
+            hash(1);
+            with_hint_sink(push(&inner), || hash(2));
+            hash(3);
+        });
+        assert_eq!(outer.into_inner(), vec![hash(1), hash(3)]);
+        assert_eq!(inner.into_inner(), vec![hash(2)]);
+
+        // sinks are per thread and popped on unwind
+        let caught = RefCell::new(vec![]);
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            with_hint_sink(push(&caught), || {
+                thread::scope(|scope| {
+                    scope.spawn(|| {
+                        let own = RefCell::new(vec![]);
+                        with_hint_sink(push(&own), || hash(4));
+                        assert_eq!(own.into_inner(), vec![hash(4)]);
+                    });
+                });
+                hash(5);
+                panic!("unwind");
+            })
+        }));
+        assert!(result.is_err());
+        hash(6);
+        assert_eq!(caught.into_inner(), vec![hash(5)]);
+    }
 }